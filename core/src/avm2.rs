@@ -9,6 +9,7 @@ use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::script::{Script, TranslationUnit};
 use crate::context::{GcContext, UpdateContext};
 use crate::display_object::{DisplayObject, DisplayObjectWeak, TDisplayObject};
+use crate::limits::MAX_E4X_NODES;
 use crate::string::AvmString;
 
 use fnv::FnvHashMap;
@@ -86,6 +87,9 @@ pub struct Avm2<'gc> {
     /// The Flash Player version we're emulating.
     player_version: u8,
 
+    /// The maximum number of nodes (including attributes) in an E4X tree.
+    max_e4x_nodes: usize,
+
     /// Values currently present on the operand stack.
     stack: Vec<Value<'gc>>,
 
@@ -165,6 +169,7 @@ impl<'gc> Avm2<'gc> {
 
         Self {
             player_version,
+            max_e4x_nodes: MAX_E4X_NODES,
             stack: Vec::new(),
             scope_stack: Vec::new(),
             call_stack: GcCell::new(context.gc_context, CallStack::new()),
@@ -628,6 +633,14 @@ impl<'gc> Avm2<'gc> {
 
     #[cfg(not(feature = "avm_debug"))]
     pub const fn set_show_debug_output(&self, _visible: bool) {}
+
+    pub fn max_e4x_nodes(&self) -> usize {
+        self.max_e4x_nodes
+    }
+
+    pub fn set_max_e4x_nodes(&mut self, max_e4x_nodes: usize) {
+        self.max_e4x_nodes = max_e4x_nodes;
+    }
 }
 
 /// If the provided `DisplayObjectWeak` should have frames run, returns
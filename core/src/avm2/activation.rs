@@ -1116,7 +1116,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             result
         } else if let Err(e) = op {
             tracing::error!("Parse error: {:?}", e);
-            Err(Error::RustError(Box::new(e)))
+            Err(Error::rust_error(e))
         } else {
             unreachable!();
         }
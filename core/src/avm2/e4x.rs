@@ -6,13 +6,17 @@ use std::{
 use gc_arena::{Collect, GcCell, MutationContext};
 use quick_xml::{
     events::{BytesStart, Event},
+    name::PrefixDeclaration,
     Reader,
 };
 
 use crate::{avm2::TObject, xml::custom_unescape};
 
 use super::{
-    error::type_error, object::E4XOrXml, string::AvmString, Activation, Error, Multiname, Value,
+    error::{error, type_error},
+    object::{E4XOrXml, NamespaceObject},
+    string::AvmString,
+    Activation, Error, Multiname, Namespace, Object, Value,
 };
 use crate::string::{WStr, WString};
 
@@ -27,6 +31,17 @@ pub struct E4XNode<'gc>(GcCell<'gc, E4XNodeData<'gc>>);
 pub struct E4XNodeData<'gc> {
     parent: Option<E4XNode<'gc>>,
     local_name: Option<AvmString<'gc>>,
+    /// The namespace URI of this node's name, if it is in one.
+    namespace: Option<AvmString<'gc>>,
+    /// The namespaces declared on this node (via `xmlns` attributes).
+    namespaces: Vec<E4XNamespace<'gc>>,
+    /// For a node without a parent, the number of nodes (including attributes) in its
+    /// tree, which is what the E4X node limit applies to.
+    ///
+    /// This is set when parsing or copying a tree, and kept up to date by the methods
+    /// that add and remove children and attributes. It is not meaningful for nodes
+    /// that have a parent.
+    tree_size: usize,
     kind: E4XNodeKind<'gc>,
 }
 
@@ -36,11 +51,39 @@ impl<'gc> Debug for E4XNodeData<'gc> {
             // Don't print the actual parent, to avoid infinite recursion
             .field("parent", &self.parent.is_some())
             .field("local_name", &self.local_name)
+            .field("namespace", &self.namespace)
+            .field("namespaces", &self.namespaces)
             .field("kind", &self.kind)
             .finish()
     }
 }
 
+/// A namespace declaration on an element, e.g. `xmlns:prefix="uri"`.
+///
+/// The default namespace (`xmlns="uri"`) has an empty prefix.
+#[derive(Copy, Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct E4XNamespace<'gc> {
+    pub prefix: AvmString<'gc>,
+    pub uri: AvmString<'gc>,
+}
+
+impl<'gc> E4XNamespace<'gc> {
+    /// Creates an AS3 `Namespace` object for this declaration.
+    pub fn as_namespace_object(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Object<'gc>, Error<'gc>> {
+        let namespace = Namespace::package(self.uri, &mut activation.borrow_gc());
+        let object = NamespaceObject::from_namespace(activation, namespace)?;
+        object
+            .as_namespace_object()
+            .unwrap()
+            .set_prefix(activation.context.gc_context, Some(self.prefix));
+        Ok(object)
+    }
+}
+
 fn malformed_element<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
     Error::AvmError(
         type_error(
@@ -52,6 +95,17 @@ fn malformed_element<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
     )
 }
 
+fn node_limit_exceeded<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    Error::AvmError(
+        error(
+            activation,
+            "Error #1000: The system is out of memory.",
+            1000,
+        )
+        .expect("Failed to construct XML Error"),
+    )
+}
+
 #[derive(Collect, Debug)]
 #[collect(no_drop)]
 pub enum E4XNodeKind<'gc> {
@@ -73,6 +127,9 @@ impl<'gc> E4XNode<'gc> {
             E4XNodeData {
                 parent: None,
                 local_name: None,
+                namespace: None,
+                namespaces: Vec::new(),
+                tree_size: 1,
                 kind: E4XNodeKind::Element {
                     attributes: vec![],
                     children: vec![],
@@ -87,17 +144,27 @@ impl<'gc> E4XNode<'gc> {
             E4XNodeData {
                 parent,
                 local_name: None,
+                namespace: None,
+                namespaces: Vec::new(),
+                tree_size: 1,
                 kind: E4XNodeKind::Text(text),
             },
         ))
     }
 
-    pub fn element(mc: MutationContext<'gc, '_>, name: AvmString<'gc>, parent: Self) -> Self {
+    pub fn element(
+        mc: MutationContext<'gc, '_>,
+        name: AvmString<'gc>,
+        parent: Option<Self>,
+    ) -> Self {
         E4XNode(GcCell::new(
             mc,
             E4XNodeData {
-                parent: Some(parent),
+                parent,
                 local_name: Some(name),
+                namespace: None,
+                namespaces: Vec::new(),
+                tree_size: 1,
                 kind: E4XNodeKind::Element {
                     attributes: vec![],
                     children: vec![],
@@ -109,6 +176,7 @@ impl<'gc> E4XNode<'gc> {
     pub fn attribute(
         mc: MutationContext<'gc, '_>,
         name: AvmString<'gc>,
+        namespace: Option<AvmString<'gc>>,
         value: AvmString<'gc>,
         parent: E4XNode<'gc>,
     ) -> Self {
@@ -117,13 +185,16 @@ impl<'gc> E4XNode<'gc> {
             E4XNodeData {
                 parent: Some(parent),
                 local_name: Some(name),
+                namespace,
+                namespaces: Vec::new(),
+                tree_size: 1,
                 kind: E4XNodeKind::Attribute(value),
             },
         ))
     }
 
     pub fn equals(&self, other: &Self) -> bool {
-        if self.local_name() != other.local_name() {
+        if self.local_name() != other.local_name() || self.namespace() != other.namespace() {
             return false;
         }
 
@@ -190,12 +261,28 @@ impl<'gc> E4XNode<'gc> {
                 children: children.iter().map(|child| child.deep_copy(mc)).collect(),
             },
         };
+        let tree_size = match &kind {
+            E4XNodeKind::Element {
+                attributes,
+                children,
+            } => {
+                1 + attributes.len()
+                    + children
+                        .iter()
+                        .map(|child| child.0.read().tree_size)
+                        .sum::<usize>()
+            }
+            _ => 1,
+        };
 
         let node = E4XNode(GcCell::new(
             mc,
             E4XNodeData {
                 parent: None,
                 local_name: this.local_name,
+                namespace: this.namespace,
+                namespaces: this.namespaces.clone(),
+                tree_size,
                 kind,
             },
         ));
@@ -219,67 +306,209 @@ impl<'gc> E4XNode<'gc> {
         node
     }
 
-    pub fn remove_all_children(&self, gc_context: MutationContext<'gc, '_>) {
-        let mut this = self.0.write(gc_context);
-        if let E4XNodeKind::Element { children, .. } = &mut this.kind {
-            for child in children.iter_mut() {
-                let mut child_data = child.0.write(gc_context);
-                child_data.parent = None;
+    /// Merges adjacent text children of this element and removes empty text children,
+    /// recursing into child elements. This is `[[Normalize]]` from E4X.
+    pub fn normalize(&self, gc_context: MutationContext<'gc, '_>) {
+        let children = match &*self.kind() {
+            E4XNodeKind::Element { children, .. } => children.clone(),
+            _ => return,
+        };
+
+        // The text nodes of this element don't depend on those of its children.
+        for child in &children {
+            child.normalize(gc_context);
+        }
+
+        let mut removed = vec![];
+        if let E4XNodeKind::Element { children, .. } = &mut self.0.write(gc_context).kind {
+            let mut index = 0;
+            while index < children.len() {
+                let child = children[index];
+                let Some(mut text) = child.text_content() else {
+                    index += 1;
+                    continue;
+                };
+
+                while let Some(next) = children.get(index + 1).and_then(E4XNode::text_content) {
+                    text = AvmString::concat(gc_context, text, next);
+                    removed.push(children.remove(index + 1));
+                }
+
+                if text.is_empty() {
+                    removed.push(children.remove(index));
+                } else {
+                    child.set_text_content(gc_context, text);
+                    index += 1;
+                }
             }
-            children.clear()
+        }
+
+        for node in removed {
+            node.detach_from(*self, gc_context);
+        }
+    }
+
+    pub fn remove_all_children(&self, gc_context: MutationContext<'gc, '_>) {
+        let removed = match &mut self.0.write(gc_context).kind {
+            E4XNodeKind::Element { children, .. } => std::mem::take(children),
+            _ => return,
+        };
+
+        for child in removed {
+            child.detach_from(*self, gc_context);
         }
     }
 
     pub fn remove_child(&self, gc_context: MutationContext<'gc, '_>, child: &Self) {
-        let mut this = self.0.write(gc_context);
-        if let E4XNodeKind::Element { children, .. } = &mut this.kind {
+        if let E4XNodeKind::Element { children, .. } = &mut self.0.write(gc_context).kind {
             children.retain(|c| !GcCell::ptr_eq(c.0, child.0));
         }
+        child.detach_from(*self, gc_context);
     }
 
     pub fn remove_attribute(&self, gc_context: MutationContext<'gc, '_>, attribute: &Self) {
-        let mut this = self.0.write(gc_context);
-        if let E4XNodeKind::Element { attributes, .. } = &mut this.kind {
+        if let E4XNodeKind::Element { attributes, .. } = &mut self.0.write(gc_context).kind {
             attributes.retain(|a| !GcCell::ptr_eq(a.0, attribute.0));
         }
+        attribute.detach_from(*self, gc_context);
+    }
+
+    /// Adds a newly created attribute to this element, making this node its parent.
+    pub fn append_attribute(&self, gc_context: MutationContext<'gc, '_>, attribute: Self) {
+        if let E4XNodeKind::Element { attributes, .. } = &mut self.0.write(gc_context).kind {
+            attributes.push(attribute);
+        } else {
+            return;
+        }
+
+        attribute.set_parent(Some(*self), gc_context);
+        self.adjust_tree_size(gc_context, 1, 0);
+        self.debug_assert_parent_of(attribute);
     }
 
     pub fn append_child(
         &self,
         gc_context: MutationContext<'gc, '_>,
         child: Self,
+    ) -> Result<(), Error<'gc>> {
+        self.add_child(gc_context, None, child)
+    }
+
+    /// Inserts a child at the given index of this element's children,
+    /// making this node its parent. The index is clamped to the number of children.
+    pub fn insert_child(
+        &self,
+        gc_context: MutationContext<'gc, '_>,
+        index: usize,
+        child: Self,
+    ) -> Result<(), Error<'gc>> {
+        self.add_child(gc_context, Some(index), child)
+    }
+
+    /// Adds a child at the given index of this element's children, or at the end,
+    /// moving its nodes from the tree it was in (if any) into this node's tree.
+    fn add_child(
+        &self,
+        gc_context: MutationContext<'gc, '_>,
+        index: Option<usize>,
+        child: Self,
+    ) -> Result<(), Error<'gc>> {
+        let size = child.count_nodes(usize::MAX);
+        let old_parent = child.parent();
+
+        self.link_child(gc_context, index, child)?;
+
+        // If the child was already in this tree, this cancels out.
+        if let Some(old_parent) = old_parent {
+            old_parent.adjust_tree_size(gc_context, 0, size);
+        }
+        self.adjust_tree_size(gc_context, size, 0);
+        Ok(())
+    }
+
+    /// Adds a child like `add_child`, but without updating the size of any tree.
+    /// Parsing uses this directly, since it counts the nodes of the tree it builds.
+    fn link_child(
+        &self,
+        gc_context: MutationContext<'gc, '_>,
+        index: Option<usize>,
+        child: Self,
     ) -> Result<(), Error<'gc>> {
         let mut this = self.0.write(gc_context);
         let mut child_data = match child.0.try_write(gc_context) {
             Ok(data) => data,
             Err(_) => {
-                return Err(Error::RustError(
-                    format!(
-                        "Circular write in append_child with self={:?} child={:?}",
-                        self, child
-                    )
-                    .into(),
-                ))
+                return Err(Error::rust_error(format!(
+                    "Circular write in add_child with self={:?} child={:?}",
+                    self, child
+                )))
             }
         };
 
         child_data.parent = Some(*self);
 
         match &mut this.kind {
-            E4XNodeKind::Element { children, .. } => {
-                children.push(child);
-            }
+            E4XNodeKind::Element { children, .. } => match index {
+                Some(index) => children.insert(index.min(children.len()), child),
+                None => children.push(child),
+            },
             _ => {
                 // FIXME - figure out exactly when appending is allowed in FP,
                 // and throw the proper AVM error.
-                return Err(Error::RustError(
-                    format!("Cannot append child {child:?} to node {:?}", this.kind).into(),
-                ));
+                return Err(Error::rust_error(format!(
+                    "Cannot add child {child:?} to node {:?}",
+                    this.kind
+                )));
             }
         }
+
+        drop(child_data);
+        drop(this);
+        self.debug_assert_parent_of(child);
         Ok(())
     }
 
+    /// Takes this node, which was just removed from the children or attributes of
+    /// `parent`, out of the tree of `parent`, making it the root of its own tree.
+    ///
+    /// Nothing happens if this node was added to another parent in the meantime.
+    fn detach_from(&self, parent: E4XNode<'gc>, gc_context: MutationContext<'gc, '_>) {
+        if !self.parent().map_or(false, |p| E4XNode::ptr_eq(p, parent)) {
+            return;
+        }
+
+        let size = self.count_nodes(usize::MAX);
+        let mut data = self.0.write(gc_context);
+        data.parent = None;
+        data.tree_size = size;
+        drop(data);
+
+        parent.adjust_tree_size(gc_context, 0, size);
+    }
+
+    /// Records that `added` nodes were put into, and `removed` nodes taken out of,
+    /// the tree of this node.
+    fn adjust_tree_size(&self, gc_context: MutationContext<'gc, '_>, added: usize, removed: usize) {
+        let root = self.root();
+        let mut root = root.0.write(gc_context);
+        root.tree_size = (root.tree_size + added).saturating_sub(removed);
+    }
+
+    /// In debug builds, checks that `child` has this node as its parent.
+    ///
+    /// Keeping the parent pointer of a newly added child in sync is the responsibility
+    /// of the mutation methods, so they should call this for each node they add.
+    /// Only the given pair is checked, since E4X allows a node to be appended elsewhere
+    /// without being removed from its previous parent.
+    pub fn debug_assert_parent_of(&self, child: E4XNode<'gc>) {
+        debug_assert!(
+            child
+                .parent()
+                .map_or(false, |parent| E4XNode::ptr_eq(parent, *self)),
+            "E4X node {child:?} is a child of {self:?}, but has a different parent"
+        );
+    }
+
     /// Parses a value provided to `XML`/`XMLList` into a list of nodes.
     /// The caller is responsible for validating that the number of top-level nodes
     /// is correct (for XML, there should be exactly one.)
@@ -302,26 +531,58 @@ impl<'gc> E4XNode<'gc> {
         };
 
         let data_utf8 = string.to_utf8_lossy();
+
         let mut parser = Reader::from_str(&data_utf8);
         let mut open_tags: Vec<E4XNode<'gc>> = vec![];
 
-        // FIXME - look these up from static property and settings
-        let ignore_comments = true;
-        let ignore_processing_instructions = true;
-        let ignore_white = true;
+        let xml_class = activation.avm2().classes().xml;
+        let ignore_comments = xml_class
+            .get_public_property("ignoreComments", activation)?
+            .coerce_to_boolean();
+        let ignore_processing_instructions = xml_class
+            .get_public_property("ignoreProcessingInstructions", activation)?
+            .coerce_to_boolean();
+        let ignore_white = xml_class
+            .get_public_property("ignoreWhitespace", activation)?
+            .coerce_to_boolean();
 
         let mut top_level = vec![];
 
         // This can't be a closure that captures these variables, because we need to modify them
         // outside of this body.
+        let mut node_count = 0;
+
+        fn count_nodes<'gc>(
+            node: E4XNode<'gc>,
+            open_tags: &[E4XNode<'gc>],
+            node_count: &mut usize,
+            activation: &mut Activation<'_, 'gc>,
+        ) -> Result<(), Error<'gc>> {
+            let max_nodes = activation.avm2().max_e4x_nodes();
+            let size = node.count_nodes(max_nodes);
+            *node_count += size;
+            if *node_count > max_nodes {
+                return Err(node_limit_exceeded(activation));
+            }
+
+            // The node will end up in the tree of the outermost open tag.
+            if let Some(root) = open_tags.first() {
+                root.0.write(activation.context.gc_context).tree_size += size;
+            }
+            Ok(())
+        }
+
         fn push_childless_node<'gc>(
             node: E4XNode<'gc>,
             open_tags: &mut [E4XNode<'gc>],
             top_level: &mut Vec<E4XNode<'gc>>,
+            node_count: &mut usize,
             activation: &mut Activation<'_, 'gc>,
         ) -> Result<(), Error<'gc>> {
+            count_nodes(node, open_tags, node_count, activation)?;
+
             if let Some(current_tag) = open_tags.last_mut() {
-                current_tag.append_child(activation.context.gc_context, node)?;
+                current_tag.link_child(activation.context.gc_context, None, node)?;
             }
 
             if open_tags.is_empty() {
@@ -358,6 +619,7 @@ impl<'gc> E4XNode<'gc> {
             open_tags: &mut [E4XNode<'gc>],
             top_level: &mut Vec<E4XNode<'gc>>,
             is_text: bool,
+            node_count: &mut usize,
             activation: &mut Activation<'_, 'gc>,
         ) -> Result<(), Error<'gc>> {
             let is_whitespace_char = |c: &u8| matches!(*c, b'\t' | b'\n' | b'\r' | b' ');
@@ -376,6 +638,9 @@ impl<'gc> E4XNode<'gc> {
                     E4XNodeData {
                         parent: None,
                         local_name: None,
+                        namespace: None,
+                        namespaces: Vec::new(),
+                        tree_size: 1,
                         kind: if is_text {
                             E4XNodeKind::Text(text)
                         } else {
@@ -383,7 +648,7 @@ impl<'gc> E4XNode<'gc> {
                         },
                     },
                 ));
-                push_childless_node(node, open_tags, top_level, activation)?;
+                push_childless_node(node, open_tags, top_level, node_count, activation)?;
             }
             Ok(())
         }
@@ -395,18 +660,35 @@ impl<'gc> E4XNode<'gc> {
 
             match &event {
                 Event::Start(bs) => {
-                    let child = E4XNode::from_start_event(activation, bs, parser.decoder())
-                        .map_err(|_| malformed_element(activation))?;
+                    let child = E4XNode::from_start_event(
+                        activation,
+                        bs,
+                        parser.decoder(),
+                        open_tags.last().copied(),
+                    )
+                    .map_err(|_| malformed_element(activation))?;
+                    count_nodes(child, &open_tags, &mut node_count, activation)?;
 
                     if let Some(current_tag) = open_tags.last_mut() {
-                        current_tag.append_child(activation.context.gc_context, child)?;
+                        current_tag.link_child(activation.context.gc_context, None, child)?;
                     }
                     open_tags.push(child);
                 }
                 Event::Empty(bs) => {
-                    let node = E4XNode::from_start_event(activation, bs, parser.decoder())
-                        .map_err(|_| malformed_element(activation))?;
-                    push_childless_node(node, &mut open_tags, &mut top_level, activation)?;
+                    let node = E4XNode::from_start_event(
+                        activation,
+                        bs,
+                        parser.decoder(),
+                        open_tags.last().copied(),
+                    )
+                    .map_err(|_| malformed_element(activation))?;
+                    push_childless_node(
+                        node,
+                        &mut open_tags,
+                        &mut top_level,
+                        &mut node_count,
+                        activation,
+                    )?;
                 }
                 Event::End(_) => {
                     let node = open_tags.pop().unwrap();
@@ -423,6 +705,7 @@ impl<'gc> E4XNode<'gc> {
                         &mut open_tags,
                         &mut top_level,
                         true,
+                        &mut node_count,
                         activation,
                     )?;
                 }
@@ -434,6 +717,7 @@ impl<'gc> E4XNode<'gc> {
                         &mut open_tags,
                         &mut top_level,
                         false,
+                        &mut node_count,
                         activation,
                     )?;
                 }
@@ -457,11 +741,20 @@ impl<'gc> E4XNode<'gc> {
                         E4XNodeData {
                             parent: None,
                             local_name: None,
+                            namespace: None,
+                            namespaces: Vec::new(),
+                            tree_size: 1,
                             kind,
                         },
                     ));
 
-                    push_childless_node(node, &mut open_tags, &mut top_level, activation)?;
+                    push_childless_node(
+                        node,
+                        &mut open_tags,
+                        &mut top_level,
+                        &mut node_count,
+                        activation,
+                    )?;
                 }
                 // These are completely ignored by AVM2
                 Event::Decl(_) | Event::DocType(_) => {}
@@ -475,39 +768,86 @@ impl<'gc> E4XNode<'gc> {
     ///
     /// The returned node will always be an `Element`, and it must only contain
     /// valid encoded UTF-8 data. (Other encoding support is planned later.)
+    ///
+    /// `parent` is the element this node will be added to, which is used to
+    /// resolve namespace prefixes that aren't declared on this element itself.
     pub fn from_start_event(
         activation: &mut Activation<'_, 'gc>,
         bs: &BytesStart<'_>,
         decoder: quick_xml::Decoder,
+        parent: Option<E4XNode<'gc>>,
     ) -> Result<Self, quick_xml::Error> {
-        // FIXME - handle namespace
-        let name =
-            AvmString::new_utf8_bytes(activation.context.gc_context, bs.local_name().into_inner());
+        let mc = activation.context.gc_context;
+        let name = AvmString::new_utf8_bytes(mc, bs.local_name().into_inner());
+
+        let attributes: Vec<_> = bs.attributes().collect::<Result<_, _>>()?;
+
+        // Namespace declarations are not attributes in E4X, and they can appear
+        // after the attributes that use them, so they have to be collected first.
+        let mut namespaces = Vec::new();
+        for attribute in &attributes {
+            if let Some(prefix) = attribute.key.as_namespace_binding() {
+                let prefix = match prefix {
+                    PrefixDeclaration::Default => AvmString::default(),
+                    PrefixDeclaration::Named(prefix) => AvmString::new_utf8_bytes(mc, prefix),
+                };
+                let uri = custom_unescape(&attribute.value, decoder)?;
+                let uri = AvmString::new_utf8_bytes(mc, uri.as_bytes());
+                namespaces.push(E4XNamespace { prefix, uri });
+            }
+        }
+
+        let resolve_prefix = |prefix: AvmString<'gc>| {
+            namespaces
+                .iter()
+                .find(|ns| ns.prefix == prefix)
+                .map(|ns| ns.uri)
+                .or_else(|| parent.and_then(|parent| parent.lookup_namespace_uri(prefix)))
+                .filter(|uri| !uri.is_empty())
+        };
+
+        // Unprefixed element names are in the default namespace, if there is one.
+        let namespace = resolve_prefix(
+            bs.name()
+                .prefix()
+                .map(|prefix| AvmString::new_utf8_bytes(mc, prefix.into_inner()))
+                .unwrap_or_default(),
+        );
 
         let mut attribute_nodes = Vec::new();
+        for attribute in &attributes {
+            if attribute.key.as_namespace_binding().is_some() {
+                continue;
+            }
 
-        let attributes: Result<Vec<_>, _> = bs.attributes().collect();
-        for attribute in attributes? {
-            let key = AvmString::new_utf8_bytes(
-                activation.context.gc_context,
-                attribute.key.into_inner(),
-            );
             let value_str = custom_unescape(&attribute.value, decoder)?;
-            let value =
-                AvmString::new_utf8_bytes(activation.context.gc_context, value_str.as_bytes());
+            let value = AvmString::new_utf8_bytes(mc, value_str.as_bytes());
+
+            let key = AvmString::new_utf8_bytes(mc, attribute.key.local_name().into_inner());
+
+            // Unlike elements, unprefixed attributes are never in a namespace.
+            let attribute_namespace = attribute.key.prefix().and_then(|prefix| {
+                resolve_prefix(AvmString::new_utf8_bytes(mc, prefix.into_inner()))
+            });
 
             let attribute_data = E4XNodeData {
                 parent: None,
                 local_name: Some(key),
+                namespace: attribute_namespace,
+                namespaces: Vec::new(),
+                tree_size: 1,
                 kind: E4XNodeKind::Attribute(value),
             };
-            let attribute = E4XNode(GcCell::new(activation.context.gc_context, attribute_data));
+            let attribute = E4XNode(GcCell::new(mc, attribute_data));
             attribute_nodes.push(attribute);
         }
 
         let data = E4XNodeData {
             parent: None,
             local_name: Some(name),
+            namespace,
+            namespaces,
+            tree_size: 1 + attribute_nodes.len(),
             kind: E4XNodeKind::Element {
                 attributes: attribute_nodes,
                 children: Vec::new(),
@@ -530,6 +870,222 @@ impl<'gc> E4XNode<'gc> {
         self.0.read().local_name
     }
 
+    /// The namespace URI of this node's name, if it is in one.
+    pub fn namespace(&self) -> Option<AvmString<'gc>> {
+        self.0.read().namespace
+    }
+
+    /// The namespaces declared on this node itself.
+    pub fn namespaces(&self) -> Ref<'_, Vec<E4XNamespace<'gc>>> {
+        Ref::map(self.0.read(), |r| &r.namespaces)
+    }
+
+    /// The topmost ancestor of this node, or this node itself if it has no parent.
+    pub fn root(&self) -> E4XNode<'gc> {
+        let mut node = *self;
+        while let Some(parent) = node.parent() {
+            node = parent;
+        }
+        node
+    }
+
+    /// Counts the nodes (including attributes) in the subtree rooted at this node.
+    ///
+    /// Counting stops as soon as more than `limit` nodes have been seen.
+    pub fn count_nodes(&self, limit: usize) -> usize {
+        let mut count = 0;
+        let mut pending = vec![*self];
+        while let Some(node) = pending.pop() {
+            count += 1;
+            if count > limit {
+                break;
+            }
+            if let E4XNodeKind::Element {
+                children,
+                attributes,
+            } = &*node.kind()
+            {
+                pending.extend(attributes.iter().copied());
+                pending.extend(children.iter().copied());
+            }
+        }
+        count
+    }
+
+    /// Throws an error if taking the `removed` nodes out of this node's tree and putting
+    /// the `added` nodes into it would grow the tree beyond the E4X node limit.
+    ///
+    /// Only the nodes being moved are counted, so this doesn't depend on the size of the tree.
+    /// Nothing is changed, the methods that add and remove children keep the size up to date.
+    pub fn check_tree_size(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+        removed: &[E4XNode<'gc>],
+        added: &[E4XNode<'gc>],
+    ) -> Result<(), Error<'gc>> {
+        let max_nodes = activation.avm2().max_e4x_nodes();
+        let root = self.root();
+
+        let removed_size = removed.iter().map(|node| node.count_nodes(max_nodes)).sum();
+        let added_size = added
+            .iter()
+            .filter(|node| {
+                // Nodes that stay in this tree are only moved around within it.
+                let stays_in_tree = node
+                    .parent()
+                    .map_or(false, |parent| E4XNode::ptr_eq(parent.root(), root));
+                let is_removed = removed.iter().any(|r| E4XNode::ptr_eq(*r, **node));
+                !stays_in_tree || is_removed
+            })
+            .map(|node| node.count_nodes(max_nodes))
+            .sum();
+
+        self.check_tree_growth(activation, removed_size, added_size)
+    }
+
+    /// Throws an error if this node's tree would grow beyond the E4X node limit once
+    /// `removed` of its nodes are taken out and `added` new nodes are put into it.
+    pub fn check_tree_growth(
+        &self,
+        activation: &mut Activation<'_, 'gc>,
+        removed: usize,
+        added: usize,
+    ) -> Result<(), Error<'gc>> {
+        let size = self.root().0.read().tree_size.saturating_sub(removed) + added;
+        if size > activation.avm2().max_e4x_nodes() {
+            return Err(node_limit_exceeded(activation));
+        }
+        Ok(())
+    }
+
+    /// The namespaces in scope for this node, collected from this node and
+    /// all of its ancestors, in document order (outermost first). Declarations
+    /// closer to this node shadow declarations of the same prefix further up
+    /// the tree, and take their place in the order.
+    pub fn in_scope_namespaces(&self) -> Vec<E4XNamespace<'gc>> {
+        let mut ancestors = vec![*self];
+        while let Some(parent) = ancestors.last().and_then(|node| node.parent()) {
+            ancestors.push(parent);
+        }
+
+        let mut in_scope: Vec<E4XNamespace<'gc>> = Vec::new();
+        for node in ancestors.iter().rev() {
+            for namespace in node.namespaces().iter() {
+                in_scope.retain(|ns| ns.prefix != namespace.prefix);
+                in_scope.push(*namespace);
+            }
+        }
+        in_scope
+    }
+
+    /// The namespaces in scope for this node, with the declarations closest to
+    /// this node first. Used for lookups, where the nearest declaration wins.
+    fn nearest_namespaces(&self) -> Vec<E4XNamespace<'gc>> {
+        let mut in_scope: Vec<E4XNamespace<'gc>> = Vec::new();
+        let mut current = Some(*self);
+        while let Some(node) = current {
+            for namespace in node.namespaces().iter() {
+                if !in_scope.iter().any(|ns| ns.prefix == namespace.prefix) {
+                    in_scope.push(*namespace);
+                }
+            }
+            current = node.parent();
+        }
+        in_scope
+    }
+
+    /// Looks up the namespace URI bound to `prefix` in the scope of this node.
+    pub fn lookup_namespace_uri(&self, prefix: AvmString<'gc>) -> Option<AvmString<'gc>> {
+        self.nearest_namespaces()
+            .into_iter()
+            .find(|ns| ns.prefix == prefix)
+            .map(|ns| ns.uri)
+    }
+
+    /// Looks up a prefix bound to the namespace `uri` in the scope of this node.
+    ///
+    /// The default namespace (with an empty prefix) is only considered if
+    /// `allow_default` is set, since it never applies to attributes.
+    pub fn lookup_prefix(
+        &self,
+        uri: AvmString<'gc>,
+        allow_default: bool,
+    ) -> Option<AvmString<'gc>> {
+        self.nearest_namespaces()
+            .into_iter()
+            .find(|ns| ns.uri == uri && (allow_default || !ns.prefix.is_empty()))
+            .map(|ns| ns.prefix)
+    }
+
+    /// Declares `namespace` on this element, replacing any existing declaration
+    /// with the same prefix in place. Nodes other than elements are left unchanged.
+    pub fn add_namespace(&self, mc: MutationContext<'gc, '_>, namespace: E4XNamespace<'gc>) {
+        let mut this = self.0.write(mc);
+        if !matches!(this.kind, E4XNodeKind::Element { .. }) {
+            return;
+        }
+
+        // The default namespace can't be redeclared as empty on a node without a namespace.
+        if namespace.prefix.is_empty() && this.namespace.unwrap_or_default().is_empty() {
+            return;
+        }
+
+        if let Some(existing) = this
+            .namespaces
+            .iter_mut()
+            .find(|ns| ns.prefix == namespace.prefix)
+        {
+            *existing = namespace;
+        } else {
+            this.namespaces.push(namespace);
+        }
+    }
+
+    /// Removes the declarations of the namespace `uri` from this element and its
+    /// descendants, unless the name of one of them (or of their attributes) is
+    /// still in that namespace. If `prefix` is given, only declarations with that
+    /// prefix are removed.
+    pub fn remove_namespace(
+        &self,
+        mc: MutationContext<'gc, '_>,
+        prefix: Option<AvmString<'gc>>,
+        uri: AvmString<'gc>,
+    ) {
+        if !matches!(&*self.kind(), E4XNodeKind::Element { .. }) || self.references_namespace(uri) {
+            return;
+        }
+
+        let mut pending = vec![*self];
+        while let Some(node) = pending.pop() {
+            let mut data = node.0.write(mc);
+            data.namespaces
+                .retain(|ns| ns.uri != uri || prefix.map_or(false, |prefix| ns.prefix != prefix));
+            if let E4XNodeKind::Element { children, .. } = &data.kind {
+                pending.extend(children.iter().copied());
+            }
+        }
+    }
+
+    /// Checks whether the name of this node, or of any of its attributes or
+    /// descendants, is in the namespace `uri`.
+    fn references_namespace(&self, uri: AvmString<'gc>) -> bool {
+        let mut pending = vec![*self];
+        while let Some(node) = pending.pop() {
+            if node.namespace().map_or(false, |ns| ns == uri) {
+                return true;
+            }
+            if let E4XNodeKind::Element {
+                children,
+                attributes,
+            } = &*node.kind()
+            {
+                pending.extend(attributes.iter().copied());
+                pending.extend(children.iter().copied());
+            }
+        }
+        false
+    }
+
     pub fn set_parent(&self, parent: Option<E4XNode<'gc>>, mc: MutationContext<'gc, '_>) {
         self.0.write(mc).parent = parent;
     }
@@ -544,7 +1100,10 @@ impl<'gc> E4XNode<'gc> {
             return false;
         }
 
-        // FIXME - we need to handle namespaces here
+        if name.has_explicit_namespace() && !self.matches_namespace(name) {
+            return false;
+        }
+
         if name.is_any_name() {
             return true;
         }
@@ -556,6 +1115,20 @@ impl<'gc> E4XNode<'gc> {
         }
     }
 
+    /// Checks whether the namespace of this node's name is one of the namespaces of `name`.
+    ///
+    /// This does not look at the local name, see `matches_name` for that.
+    pub fn matches_namespace(&self, name: &Multiname<'gc>) -> bool {
+        if name.is_any_namespace() {
+            return true;
+        }
+
+        let uri = self.namespace().unwrap_or_default();
+        name.namespace_set()
+            .iter()
+            .any(|ns| ns.as_uri_opt().unwrap_or_default() == uri)
+    }
+
     pub fn descendants(&self, name: &Multiname<'gc>, out: &mut Vec<E4XOrXml<'gc>>) {
         if let E4XNodeKind::Element {
             children,
@@ -620,7 +1193,9 @@ impl<'gc> E4XNode<'gc> {
 
                 return to_xml_string(E4XOrXml::E4X(*self), activation);
             }
-            other => Err(format!("XML.toString(): Not yet implemented for {other:?}").into()),
+            E4XNodeKind::Comment(_) | E4XNodeKind::ProcessingInstruction(_) => {
+                to_xml_string(E4XOrXml::E4X(*self), activation)
+            }
         }
     }
 
@@ -631,6 +1206,33 @@ impl<'gc> E4XNode<'gc> {
         return to_xml_string(E4XOrXml::E4X(*self), activation);
     }
 
+    /// The content of this node, if it is a `Text` node.
+    pub fn text_content(&self) -> Option<AvmString<'gc>> {
+        match &self.0.read().kind {
+            E4XNodeKind::Text(text) => Some(*text),
+            _ => None,
+        }
+    }
+
+    /// Replaces the content of this node, if it is a `Text` node.
+    pub fn set_text_content(&self, gc_context: MutationContext<'gc, '_>, text: AvmString<'gc>) {
+        if let E4XNodeKind::Text(old) = &mut self.0.write(gc_context).kind {
+            *old = text;
+        }
+    }
+
+    /// The E4X node kind of this node, as returned by `nodeKind()`.
+    pub fn node_kind(&self) -> &'static str {
+        match &self.0.read().kind {
+            E4XNodeKind::Text(_) => "text",
+            E4XNodeKind::CData(_) => "text", // cdata pretends to be text here
+            E4XNodeKind::Comment(_) => "comment",
+            E4XNodeKind::ProcessingInstruction(_) => "processing-instruction",
+            E4XNodeKind::Attribute(_) => "attribute",
+            E4XNodeKind::Element { .. } => "element",
+        }
+    }
+
     pub fn kind(&self) -> Ref<'_, E4XNodeKind<'gc>> {
         Ref::map(self.0.read(), |r| &r.kind)
     }
@@ -703,6 +1305,24 @@ pub fn escape_element_value(s: AvmString) -> WString {
     r
 }
 
+/// Writes the name of `node`, prefixed by whichever prefix is bound to its namespace
+/// in the scope of `scope`.
+fn push_qualified_name<'gc>(
+    node: E4XNode<'gc>,
+    scope: E4XNode<'gc>,
+    allow_default: bool,
+    buf: &mut WString,
+) {
+    let prefix = node
+        .namespace()
+        .and_then(|uri| scope.lookup_prefix(uri, allow_default));
+    if let Some(prefix) = prefix.filter(|prefix| !prefix.is_empty()) {
+        buf.push_str(&prefix);
+        buf.push_char(':');
+    }
+    buf.push_str(&node.local_name().unwrap());
+}
+
 fn to_xml_string_inner<'gc>(xml: E4XOrXml<'gc>, buf: &mut WString) -> Result<(), Error<'gc>> {
     // FIXME: Implement pretty printing and namespace support.
 
@@ -713,10 +1333,21 @@ fn to_xml_string_inner<'gc>(xml: E4XOrXml<'gc>, buf: &mut WString) -> Result<(),
             buf.push_str(&escape_element_value(*text));
             return Ok(());
         }
-        E4XNodeKind::Attribute(_)
-        | E4XNodeKind::Comment(_)
-        | E4XNodeKind::ProcessingInstruction(_) => {
-            return Err(format!("ToXMLString: Not yet implemented node {:?}", node_kind).into())
+        E4XNodeKind::Attribute(value) => {
+            buf.push_str(&escape_attribute_value(*value));
+            return Ok(());
+        }
+        E4XNodeKind::Comment(data) => {
+            buf.push_utf8("<!--");
+            buf.push_str(data);
+            buf.push_utf8("-->");
+            return Ok(());
+        }
+        E4XNodeKind::ProcessingInstruction(data) => {
+            buf.push_utf8("<?");
+            buf.push_str(data);
+            buf.push_utf8("?>");
+            return Ok(());
         }
         E4XNodeKind::CData(data) => {
             buf.push_utf8("<![CDATA[");
@@ -731,12 +1362,24 @@ fn to_xml_string_inner<'gc>(xml: E4XOrXml<'gc>, buf: &mut WString) -> Result<(),
     };
 
     buf.push_char('<');
-    buf.push_str(&node.local_name().unwrap());
+    push_qualified_name(*node, *node, true, buf);
+
+    for namespace in node.namespaces().iter() {
+        buf.push_utf8(" xmlns");
+        if !namespace.prefix.is_empty() {
+            buf.push_char(':');
+            buf.push_str(&namespace.prefix);
+        }
+        buf.push_char('=');
+        buf.push_char('"');
+        buf.push_str(&escape_attribute_value(namespace.uri));
+        buf.push_char('"');
+    }
 
     for attribute in attributes {
         if let E4XNodeKind::Attribute(value) = &*attribute.kind() {
             buf.push_char(' ');
-            buf.push_str(&attribute.local_name().unwrap());
+            push_qualified_name(*attribute, *node, false, buf);
             buf.push_char('=');
             buf.push_char('"');
             buf.push_str(&escape_attribute_value(*value));
@@ -756,7 +1399,7 @@ fn to_xml_string_inner<'gc>(xml: E4XOrXml<'gc>, buf: &mut WString) -> Result<(),
     }
 
     buf.push_utf8("</");
-    buf.push_str(&node.local_name().unwrap());
+    push_qualified_name(*node, *node, true, buf);
     buf.push_char('>');
 
     Ok(())
@@ -3,6 +3,7 @@ use crate::avm2::Activation;
 use crate::avm2::AvmString;
 use crate::avm2::Multiname;
 use crate::avm2::Value;
+use std::backtrace::{Backtrace, BacktraceStatus};
 use std::fmt::Debug;
 use std::mem::size_of;
 
@@ -39,6 +40,47 @@ impl<'gc> Debug for Error<'gc> {
     }
 }
 
+impl<'gc> Error<'gc> {
+    /// Wraps an internal error, capturing a backtrace of where it was created
+    /// if backtraces are enabled (e.g. with `RUST_BACKTRACE=1`).
+    pub fn rust_error(error: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Error::RustError(Box::new(BacktracedError {
+            error: error.into(),
+            backtrace: Backtrace::capture(),
+        }))
+    }
+}
+
+/// An internal error along with the backtrace captured when it was created.
+///
+/// This is kept behind the `RustError` box so that it doesn't grow `Error` itself.
+struct BacktracedError {
+    error: Box<dyn std::error::Error>,
+    backtrace: Backtrace,
+}
+
+impl Debug for BacktracedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.error)?;
+        if self.backtrace.status() == BacktraceStatus::Captured {
+            write!(f, "\n\nBacktrace:\n{}", self.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for BacktracedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for BacktracedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.error)
+    }
+}
+
 // This type is used very frequently, so make sure it doesn't unexpectedly grow.
 #[cfg(target_family = "wasm")]
 const _: () = assert!(size_of::<Result<Value<'_>, Error<'_>>>() == 24);
@@ -138,6 +180,20 @@ pub fn make_reference_error<'gc>(
     }
 }
 
+#[inline(never)]
+#[cold]
+pub fn make_error_2007<'gc>(activation: &mut Activation<'_, 'gc>, param_name: &str) -> Error<'gc> {
+    let err = type_error(
+        activation,
+        &format!("Error #2007: Parameter {} must be non-null.", param_name),
+        2007,
+    );
+    match err {
+        Ok(err) => Error::AvmError(err),
+        Err(err) => err,
+    }
+}
+
 #[inline(never)]
 #[cold]
 pub fn make_error_2008<'gc>(activation: &mut Activation<'_, 'gc>, param_name: &str) -> Error<'gc> {
@@ -155,6 +211,29 @@ pub fn make_error_2008<'gc>(activation: &mut Activation<'_, 'gc>, param_name: &s
     }
 }
 
+/// The SecurityError thrown when the sandbox rejects a socket connection to `host`.
+#[inline(never)]
+#[cold]
+pub fn make_socket_security_error<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    host: &str,
+    port: u16,
+) -> Error<'gc> {
+    let movie_url = activation.context.swf.url().to_string();
+    let err = security_error(
+        activation,
+        &format!(
+            "Error #2048: Security sandbox violation: {} cannot load data from {}:{}.",
+            movie_url, host, port
+        ),
+        2048,
+    );
+    match err {
+        Ok(err) => Error::AvmError(err),
+        Err(err) => err,
+    }
+}
+
 #[inline(never)]
 #[cold]
 pub fn range_error<'gc>(
@@ -312,18 +391,18 @@ impl<'gc> std::fmt::Display for Error<'gc> {
 
 impl<'gc, 'a> From<&'a str> for Error<'gc> {
     fn from(val: &'a str) -> Error<'gc> {
-        Error::RustError(val.into())
+        Error::rust_error(val)
     }
 }
 
 impl<'gc> From<String> for Error<'gc> {
     fn from(val: String) -> Error<'gc> {
-        Error::RustError(val.into())
+        Error::rust_error(val)
     }
 }
 
 impl<'gc> From<ruffle_render::error::Error> for Error<'gc> {
     fn from(val: ruffle_render::error::Error) -> Error<'gc> {
-        Error::RustError(val.into())
+        Error::rust_error(val)
     }
 }
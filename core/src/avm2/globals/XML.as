@@ -2,26 +2,52 @@ package {
     [Ruffle(InstanceAllocator)]
     [Ruffle(CallHandler)]
     public final dynamic class XML {
-        import __ruffle__.stub_method;
+        public static var ignoreComments:Boolean = true;
+        public static var ignoreProcessingInstructions:Boolean = true;
+        public static var ignoreWhitespace:Boolean = true;
+        public static var prettyPrinting:Boolean = true;
+        public static var prettyIndent:int = 2;
+
+        AS3 static function setSettings(settings:Object = null): void {
+            if (settings == null) {
+                settings = XML.AS3::defaultSettings();
+            }
 
-        AS3 function normalize(): XML {
-            stub_method("XML", "normalize");
-            return this;
-        }
-        
-        AS3 static function setSettings(settings:Object): void {
-            stub_method("XML", "setSettings");
+            // Properties that are missing or have the wrong type are left unchanged.
+            if (settings.ignoreComments is Boolean) {
+                XML.ignoreComments = settings.ignoreComments;
+            }
+            if (settings.ignoreProcessingInstructions is Boolean) {
+                XML.ignoreProcessingInstructions = settings.ignoreProcessingInstructions;
+            }
+            if (settings.ignoreWhitespace is Boolean) {
+                XML.ignoreWhitespace = settings.ignoreWhitespace;
+            }
+            if (settings.prettyPrinting is Boolean) {
+                XML.prettyPrinting = settings.prettyPrinting;
+            }
+            if (settings.prettyIndent is Number) {
+                XML.prettyIndent = settings.prettyIndent;
+            }
         }
 
         AS3 static function settings():Object {
-            stub_method("XML", "settings");
+            return {
+                ignoreComments: XML.ignoreComments,
+                ignoreProcessingInstructions: XML.ignoreProcessingInstructions,
+                ignoreWhitespace: XML.ignoreWhitespace,
+                prettyPrinting: XML.prettyPrinting,
+                prettyIndent: XML.prettyIndent
+            };
+        }
 
+        AS3 static function defaultSettings():Object {
             return {
                 ignoreComments: true,
                 ignoreProcessingInstructions: true,
                 ignoreWhitespace: true,
-                prettyIndent: 2,
-                prettyPrinting: true
+                prettyPrinting: true,
+                prettyIndent: 2
             };
         }
 
@@ -35,6 +61,10 @@ package {
         AS3 native function hasSimpleContent():Boolean;
         AS3 native function name():Object;
         AS3 native function namespace(prefix:String = null):*;
+        AS3 native function namespaceDeclarations():Array;
+        AS3 native function inScopeNamespaces():Array;
+        AS3 native function addNamespace(ns:*):XML;
+        AS3 native function removeNamespace(ns:*):XML;
         AS3 native function localName():Object;
         AS3 native function toXMLString():String;
         AS3 native function child(name:Object):XMLList;
@@ -47,8 +77,11 @@ package {
         AS3 native function attribute(name:*):XMLList;
         AS3 native function nodeKind():String;
         AS3 native function appendChild(child:Object):XML;
+        AS3 native function prependChild(child:Object):XML;
+        AS3 native function setChildren(value:Object):XML;
         AS3 native function descendants(name:Object = "*"):XMLList;
         AS3 native function text():XMLList;
+        AS3 native function normalize():XML;
         AS3 native function toString():String;
         AS3 native function length():int;
 
@@ -76,6 +109,26 @@ package {
             return self.AS3::namespace(prefix);
         }
 
+        prototype.namespaceDeclarations = function():Array {
+            var self:XML = this;
+            return self.AS3::namespaceDeclarations();
+        }
+
+        prototype.inScopeNamespaces = function():Array {
+            var self:XML = this;
+            return self.AS3::inScopeNamespaces();
+        }
+
+        prototype.addNamespace = function(ns:*):XML {
+            var self:XML = this;
+            return self.AS3::addNamespace(ns);
+        }
+
+        prototype.removeNamespace = function(ns:*):XML {
+            var self:XML = this;
+            return self.AS3::removeNamespace(ns);
+        }
+
         prototype.localName = function():Object {
             var self:XML = this;
             return self.AS3::localName();
@@ -144,6 +197,16 @@ package {
             return self.AS3::appendChild(child);
         };
 
+        prototype.prependChild = function(child:Object):XML {
+            var self:XML = this;
+            return self.AS3::prependChild(child);
+        };
+
+        prototype.setChildren = function(value:Object):XML {
+            var self:XML = this;
+            return self.AS3::setChildren(value);
+        };
+
         prototype.descendants = function(name:Object):XMLList {
             var self:XML = this;
             return self.AS3::descendants(name);
@@ -14,17 +14,21 @@ package {
         AS3 native function length():int;
         AS3 native function child(name:Object):XMLList;
         AS3 native function children():XMLList;
+        AS3 native function elements(name:Object = "*"):XMLList;
         AS3 native function copy():XMLList;
+        AS3 native function contains(value:*):Boolean;
         AS3 native function attribute(name:*):XMLList;
         AS3 native function attributes():XMLList;
         AS3 native function descendants(name:Object = "*"):XMLList;
         AS3 native function text():XMLList;
+        AS3 native function normalize():XMLList;
         AS3 native function toXMLString():String;
         AS3 native function toString():String;
 
         // The following native methods are not declared in the documentation,
         // but still exist
         AS3 native function name(): Object;
+        AS3 native function nodeKind(): String;
 
         prototype.hasComplexContent = function():Boolean {
             var self:XMLList = this;
@@ -55,6 +59,16 @@ package {
             return self.AS3::children();
         }
 
+        prototype.elements = function(name:Object = "*"):XMLList {
+            var self:XMLList = this;
+            return self.AS3::elements(name);
+        }
+
+        prototype.contains = function(value:*):Boolean {
+            var self:XMLList = this;
+            return self.AS3::contains(value);
+        }
+
         prototype.copy = function():XMLList {
             var self:XMLList = this;
             return self.AS3::copy();
@@ -85,6 +99,11 @@ package {
             return self.AS3::name();
         }
 
+        prototype.nodeKind = function(): String {
+            var self:XMLList = this;
+            return self.AS3::nodeKind();
+        }
+
         prototype.descendants = function(name:Object):XMLList {
             var self:XMLList = this;
             return self.AS3::descendants(name);
@@ -95,6 +114,11 @@ package {
             return self.AS3::text();
         }
 
+        prototype.normalize = function():XMLList {
+            var self:XMLList = this;
+            return self.AS3::normalize();
+        }
+
         public static const length:int = 1;
     }
 }
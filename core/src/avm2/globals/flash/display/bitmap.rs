@@ -132,7 +132,7 @@ pub fn set_bitmap_data<'gc>(
             bitmap_data
                 .coerce_to_object(activation)?
                 .as_bitmap_data()
-                .ok_or_else(|| Error::RustError("Argument was not a BitmapData".into()))?
+                .ok_or_else(|| Error::rust_error("Argument was not a BitmapData"))?
         };
         bitmap.set_bitmap_data(&mut activation.context, bitmap_data);
     }
@@ -391,9 +391,10 @@ pub fn create_texture<'gc>(
         let optimize_for_render_to_texture = args[3].coerce_to_boolean();
         let streaming_levels = args[4].as_integer(activation.context.gc_context)? as u32;
         let format = Context3DTextureFormat::from_wstr(&format).ok_or_else(|| {
-            Error::RustError(
-                format!("Unsupported texture format in createTexture: {:?}", format).into(),
-            )
+            Error::rust_error(format!(
+                "Unsupported texture format in createTexture: {:?}",
+                format
+            ))
         })?;
 
         let class = activation.avm2().classes().texture;
@@ -423,13 +424,10 @@ pub fn create_rectangle_texture<'gc>(
         let format = args[2].coerce_to_string(activation)?;
         let optimize_for_render_to_texture = args[3].coerce_to_boolean();
         let format = Context3DTextureFormat::from_wstr(&format).ok_or_else(|| {
-            Error::RustError(
-                format!(
-                    "Unsupported texture format in createRectangleTexture: {:?}",
-                    format
-                )
-                .into(),
-            )
+            Error::rust_error(format!(
+                "Unsupported texture format in createRectangleTexture: {:?}",
+                format
+            ))
         })?;
 
         let class = activation.avm2().classes().rectangletexture;
@@ -459,13 +457,10 @@ pub fn create_cube_texture<'gc>(
         let optimize_for_render_to_texture = args[2].coerce_to_boolean();
         let streaming_levels = args[3].as_integer(activation.context.gc_context)? as u32;
         let format = Context3DTextureFormat::from_wstr(&format).ok_or_else(|| {
-            Error::RustError(
-                format!(
-                    "Unsupported texture format in createCubeTexture: {:?}",
-                    format
-                )
-                .into(),
-            )
+            Error::rust_error(format!(
+                "Unsupported texture format in createCubeTexture: {:?}",
+                format
+            ))
         })?;
 
         return context.create_cube_texture(
@@ -1,4 +1,4 @@
-use crate::avm2::error::type_error;
+use crate::avm2::error::make_error_2007;
 use crate::avm2::object::TObject;
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::{Activation, Avm2, Error, Object, Value};
@@ -38,19 +38,11 @@ pub fn send<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if matches!(args.get_value(0), Value::Null) {
-        return Err(Error::AvmError(type_error(
-            activation,
-            "Error #2007: Parameter connectionName must be non-null.",
-            2007,
-        )?));
+        return Err(make_error_2007(activation, "connectionName"));
     }
 
     if matches!(args.get_value(1), Value::Null) {
-        return Err(Error::AvmError(type_error(
-            activation,
-            "Error #2007: Parameter methodName must be non-null.",
-            2007,
-        )?));
+        return Err(make_error_2007(activation, "methodName"));
     }
 
     avm2_stub_method!(activation, "flash.net.LocalConnection", "send");
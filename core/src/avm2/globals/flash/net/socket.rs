@@ -1,5 +1,6 @@
-use crate::avm2::bytearray::{Endian, ObjectEncoding};
-use crate::avm2::error::{io_error, make_error_2008, security_error};
+use crate::avm1::globals::system::SandboxType;
+use crate::avm2::bytearray::{Endian, EofError, ObjectEncoding};
+use crate::avm2::error::{io_error, make_error_2008, make_socket_security_error, security_error};
 pub use crate::avm2::object::socket_allocator;
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::string::AvmString;
@@ -39,6 +40,17 @@ pub fn connect<'gc>(
         .try_into()
         .map_err(|_| invalid_port_number(activation))?;
 
+    if matches!(
+        activation.context.system.sandbox_type,
+        SandboxType::LocalWithFile
+    ) {
+        return Err(make_socket_security_error(
+            activation,
+            &host.to_utf8_lossy(),
+            port,
+        ));
+    }
+
     let UpdateContext {
         sockets, navigator, ..
     } = &mut activation.context;
@@ -84,7 +96,9 @@ pub fn close<'gc>(
             .get_handle()
             .ok_or(invalid_socket_error(activation))?;
 
-        if !activation.context.sockets.is_connected(handle) {
+        // A pending connection attempt can still be cancelled.
+        let sockets = &activation.context.sockets;
+        if !sockets.is_connected(handle) && !sockets.is_connecting(handle) {
             return Err(invalid_socket_error(activation));
         }
 
@@ -102,7 +116,7 @@ pub fn get_bytes_available<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(socket) = this.as_socket() {
-        return Ok(socket.read_buffer().len().into());
+        return Ok(socket.bytes_available().into());
     }
 
     Ok(Value::Undefined)
@@ -199,18 +213,20 @@ pub fn flush<'gc>(
     if let Some(socket) = this.as_socket() {
         let handle = socket
             .get_handle()
-            .ok_or(invalid_socket_error(activation))?;
+            .ok_or_else(|| invalid_socket_error(activation))?;
+
+        // Sending on a socket that was closed or failed to connect must not silently drop the data.
         if !activation.context.sockets.is_connected(handle) {
             return Err(invalid_socket_error(activation));
         }
 
         let UpdateContext { sockets, .. } = &mut activation.context;
 
-        let mut buffer = socket.write_buffer();
-        let len = buffer.len();
-        let data = buffer.drain(..len).collect::<Vec<_>>();
-
-        sockets.send(handle, data)
+        let data = socket.write_buffer().clone();
+        if !sockets.send(handle, data) {
+            return Err(invalid_socket_error(activation));
+        }
+        socket.write_buffer().clear();
     }
 
     Ok(Value::Undefined)
@@ -364,12 +380,14 @@ pub fn read_object<'gc>(
 
         let mut bytes = socket.read_buffer();
 
+        // The object may not have been received completely yet, in which case it stays
+        // in the buffer.
         let (bytes_left, value) = match socket.object_encoding() {
             ObjectEncoding::Amf0 => {
                 let mut decoder = AMF0Decoder::default();
                 let (extra, amf) = decoder
                     .parse_single_element(&bytes)
-                    .map_err(|_| "Error: Invalid object")?;
+                    .map_err(|_| EofError.to_avm(activation))?;
                 (
                     extra.len(),
                     crate::avm2::amf::deserialize_value(activation, &amf)?,
@@ -379,7 +397,7 @@ pub fn read_object<'gc>(
                 let mut decoder = AMF3Decoder::default();
                 let (extra, amf) = decoder
                     .parse_single_element(&bytes)
-                    .map_err(|_| "Error: Invalid object")?;
+                    .map_err(|_| EofError.to_avm(activation))?;
                 (
                     extra.len(),
                     crate::avm2::amf::deserialize_value(activation, &amf)?,
@@ -19,13 +19,10 @@ pub fn instance_init<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this.as_namespace_object() {
-        let uri_value = match args {
-            [_prefix, uri] => {
-                avm2_stub_constructor!(activation, "Namespace", "Namespace prefix not supported");
-                Some(*uri)
-            }
-            [uri] => Some(*uri),
-            _ => None,
+        let (prefix_value, uri_value) = match args {
+            [prefix, uri] => (Some(*prefix), Some(*uri)),
+            [uri] => (None, Some(*uri)),
+            _ => (None, None),
         };
 
         let namespace = match uri_value {
@@ -41,6 +38,11 @@ pub fn instance_init<'gc>(
         };
 
         this.init_namespace(activation.context.gc_context, namespace);
+
+        if let Some(prefix) = prefix_value.filter(|prefix| !matches!(prefix, Value::Undefined)) {
+            let prefix = prefix.coerce_to_string(activation)?;
+            this.set_prefix(activation.context.gc_context, Some(prefix));
+        }
     }
     Ok(Value::Undefined)
 }
@@ -80,7 +82,11 @@ pub fn prefix<'gc>(
     this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    if this.as_namespace_object().is_some() {
+    if let Some(this) = this.as_namespace_object() {
+        if let Some(prefix) = this.prefix() {
+            return Ok(prefix.into());
+        }
+
         avm2_stub_getter!(activation, "Namespace", "prefix");
         return Ok("".into());
     }
@@ -1,14 +1,14 @@
 //! XML builtin and prototype
 
-use crate::avm2::e4x::{name_to_multiname, E4XNode, E4XNodeKind};
+use crate::avm2::e4x::{name_to_multiname, E4XNamespace, E4XNode, E4XNodeKind};
 use crate::avm2::error::type_error;
 pub use crate::avm2::object::xml_allocator;
 use crate::avm2::object::{
-    E4XOrXml, NamespaceObject, QNameObject, TObject, XmlListObject, XmlObject,
+    ArrayObject, E4XOrXml, NamespaceObject, QNameObject, TObject, XmlListObject, XmlObject,
 };
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::string::AvmString;
-use crate::avm2::{Activation, Error, Multiname, Object, Value};
+use crate::avm2::{Activation, ArrayStorage, Error, Multiname, Object, Value};
 use crate::avm2_stub_method;
 
 fn ill_formed_markup_err<'gc>(
@@ -83,6 +83,87 @@ pub fn namespace<'gc>(
     Ok(NamespaceObject::from_namespace(activation, namespace)?.into())
 }
 
+pub fn namespace_declarations<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
+
+    let declarations = node.namespaces().clone();
+    let mut namespaces: Vec<Value<'gc>> = Vec::with_capacity(declarations.len());
+    for declaration in declarations {
+        namespaces.push(declaration.as_namespace_object(activation)?.into());
+    }
+
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_args(&namespaces))?.into())
+}
+
+pub fn in_scope_namespaces<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
+
+    let in_scope = node.in_scope_namespaces();
+    let mut namespaces: Vec<Value<'gc>> = Vec::with_capacity(in_scope.len());
+    for namespace in in_scope {
+        namespaces.push(namespace.as_namespace_object(activation)?.into());
+    }
+
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_args(&namespaces))?.into())
+}
+
+/// Converts the argument of `addNamespace`/`removeNamespace` into a prefix
+/// (if it has one) and a namespace URI.
+fn namespace_arg<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<(Option<AvmString<'gc>>, AvmString<'gc>), Error<'gc>> {
+    if let Value::Object(object) = value {
+        if let Some(namespace) = object.as_namespace_object() {
+            return Ok((namespace.prefix(), namespace.namespace().as_uri()));
+        }
+        if let Some(qname) = object.as_qname_object() {
+            return Ok((None, qname.uri().unwrap_or_default()));
+        }
+    }
+
+    Ok((None, value.coerce_to_string(activation)?))
+}
+
+pub fn add_namespace<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let (prefix, uri) = namespace_arg(activation, args.get_value(0))?;
+
+    // A namespace without a prefix can't be declared.
+    if let Some(prefix) = prefix {
+        xml.node()
+            .add_namespace(activation.context.gc_context, E4XNamespace { prefix, uri });
+    }
+
+    Ok(xml.into())
+}
+
+pub fn remove_namespace<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let (prefix, uri) = namespace_arg(activation, args.get_value(0))?;
+    xml.node()
+        .remove_namespace(activation.context.gc_context, prefix, uri);
+    Ok(xml.into())
+}
+
 pub fn local_name<'gc>(
     _activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -296,15 +377,7 @@ pub fn node_kind<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     let xml = this.as_xml_object().unwrap();
-    let name = match &*xml.node().kind() {
-        E4XNodeKind::Text(_) => "text",
-        E4XNodeKind::CData(_) => "text", // cdata pretends to be text here
-        E4XNodeKind::Comment(_) => "comment",
-        E4XNodeKind::ProcessingInstruction(_) => "processing-instruction",
-        E4XNodeKind::Attribute(_) => "attribute",
-        E4XNodeKind::Element { .. } => "element",
-    };
-    Ok(name.into())
+    Ok(xml.node().node_kind().into())
 }
 
 pub fn append_child<'gc>(
@@ -313,25 +386,120 @@ pub fn append_child<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
 
     let child = args.get_object(activation, 0, "child")?;
-    if let Some(child) = child.as_xml_object() {
-        xml.node()
-            .append_child(activation.context.gc_context, *child.node())?;
+    let new_children = if let Some(child) = child.as_xml_object() {
+        vec![*child.node()]
     } else if let Some(list) = child.as_xml_list_object() {
         if list.target().is_some() {
             return Err("Cannot append XMLList with target".into());
         }
-        for child in &*list.children() {
-            xml.node()
-                .append_child(activation.context.gc_context, *child.node())?;
-        }
+        list.children().iter().map(|child| *child.node()).collect()
     } else {
         return Err(format!("Cannot append non-XML value {child:?}").into());
     };
+
+    node.check_tree_size(activation, &[], &new_children)?;
+    for child in new_children {
+        node.append_child(activation.context.gc_context, child)?;
+    }
     Ok(Value::Undefined)
 }
 
+/// Converts the value given to `prependChild` or `setChildren` into the nodes to add.
+fn nodes_from_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<Vec<E4XNode<'gc>>, Error<'gc>> {
+    let nodes = if let Some(child) = value.as_object().and_then(|obj| obj.as_xml_object()) {
+        vec![*child.node()]
+    } else if let Some(list) = value.as_object().and_then(|obj| obj.as_xml_list_object()) {
+        list.children().iter().map(|child| *child.node()).collect()
+    } else {
+        let text = value.coerce_to_string(activation)?;
+        vec![E4XNode::text(activation.context.gc_context, text, None)]
+    };
+    Ok(nodes)
+}
+
+/// Inserts `nodes` into the children of `node`, starting at `index`.
+/// They are inserted in order, so that the children of an XMLList keep their relative order.
+fn insert_children<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    node: E4XNode<'gc>,
+    index: usize,
+    nodes: Vec<E4XNode<'gc>>,
+) -> Result<(), Error<'gc>> {
+    for (offset, child) in nodes.into_iter().enumerate() {
+        node.insert_child(activation.context.gc_context, index + offset, child)?;
+    }
+    Ok(())
+}
+
+pub fn prepend_child<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
+
+    // Only elements can have children, everything else is left untouched.
+    if !matches!(&*node.kind(), E4XNodeKind::Element { .. }) {
+        return Ok(xml.into());
+    }
+
+    let value = args.get_value(0);
+    if let Some(list) = value.as_object().and_then(|obj| obj.as_xml_list_object()) {
+        if list.target().is_some() {
+            return Err("Cannot prepend XMLList with target".into());
+        }
+    }
+
+    let new_children = nodes_from_value(activation, value)?;
+    node.check_tree_size(activation, &[], &new_children)?;
+    insert_children(activation, node, 0, new_children)?;
+
+    Ok(xml.into())
+}
+
+pub fn set_children<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    let node = *xml.node();
+
+    // Only elements can have children, everything else is left untouched.
+    if !matches!(&*node.kind(), E4XNodeKind::Element { .. }) {
+        return Ok(xml.into());
+    }
+
+    let new_children = nodes_from_value(activation, args.get_value(0))?;
+    let old_children = match &*node.kind() {
+        E4XNodeKind::Element { children, .. } => children.clone(),
+        _ => Vec::new(),
+    };
+    node.check_tree_size(activation, &old_children, &new_children)?;
+
+    node.remove_all_children(activation.context.gc_context);
+    insert_children(activation, node, 0, new_children)?;
+
+    Ok(xml.into())
+}
+
+pub fn normalize<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml = this.as_xml_object().unwrap();
+    xml.node().normalize(activation.context.gc_context);
+    Ok(xml.into())
+}
+
 pub fn descendants<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -58,11 +58,7 @@ pub fn init<'gc>(
                 nodes.into_iter().map(E4XOrXml::E4X).collect(),
             );
         }
-        Err(e) => {
-            return Err(Error::RustError(
-                format!("Failed to parse XML: {e:?}").into(),
-            ))
-        }
+        Err(e) => return Err(Error::rust_error(format!("Failed to parse XML: {e:?}"))),
     }
 
     Ok(Value::Undefined)
@@ -155,16 +151,25 @@ pub fn child<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let list = this.as_xml_list_object().unwrap();
     let multiname = name_to_multiname(activation, &args[0], false)?;
+    // A numeric name selects the child at that index in each of our entries.
+    let index = multiname
+        .local_name()
+        .filter(|_| !multiname.is_attribute())
+        .and_then(|local_name| local_name.parse::<usize>().ok());
     let children = list.children();
     let mut sub_children = Vec::new();
     for child in &*children {
         if let E4XNodeKind::Element { ref children, .. } = &*child.node().kind() {
-            sub_children.extend(
-                children
-                    .iter()
-                    .filter(|node| node.matches_name(&multiname))
-                    .map(|node| E4XOrXml::E4X(*node)),
-            );
+            if let Some(index) = index {
+                sub_children.extend(children.get(index).map(|node| E4XOrXml::E4X(*node)));
+            } else {
+                sub_children.extend(
+                    children
+                        .iter()
+                        .filter(|node| node.matches_name(&multiname))
+                        .map(|node| E4XOrXml::E4X(*node)),
+                );
+            }
         }
     }
     Ok(XmlListObject::new(activation, sub_children, Some(list.into())).into())
@@ -186,6 +191,31 @@ pub fn children<'gc>(
     Ok(XmlListObject::new(activation, sub_children, Some(list.into())).into())
 }
 
+pub fn elements<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let list = this.as_xml_list_object().unwrap();
+    let multiname = name_to_multiname(activation, &args[0], false)?;
+    let children = list.children();
+    let mut sub_children = Vec::new();
+    for child in &*children {
+        if let E4XNodeKind::Element { ref children, .. } = &*child.node().kind() {
+            sub_children.extend(
+                children
+                    .iter()
+                    .filter(|node| {
+                        matches!(&*node.kind(), E4XNodeKind::Element { .. })
+                            && node.matches_name(&multiname)
+                    })
+                    .map(|node| E4XOrXml::E4X(*node)),
+            );
+        }
+    }
+    Ok(XmlListObject::new(activation, sub_children, Some(list.into())).into())
+}
+
 pub fn copy<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -200,6 +230,25 @@ pub fn copy<'gc>(
     Ok(XmlListObject::new(activation, children, list.target()).into())
 }
 
+pub fn contains<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let list = this.as_xml_list_object().unwrap();
+    let value = args.get_value(0);
+
+    for index in 0..list.length() {
+        if let Some(child) = list.xml_object_child(index, activation) {
+            if child.abstract_eq(&value, activation)? {
+                return Ok(true.into());
+            }
+        }
+    }
+
+    Ok(false.into())
+}
+
 pub fn attribute<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -214,13 +263,12 @@ pub fn attribute<'gc>(
     let mut sub_children = Vec::new();
     for child in &*children {
         if let E4XNodeKind::Element { ref attributes, .. } = &*child.node().kind() {
-            if let Some(found) = attributes
-                .iter()
-                .find(|node| node.matches_name(&multiname))
-                .copied()
-            {
-                sub_children.push(E4XOrXml::E4X(found));
-            }
+            sub_children.extend(
+                attributes
+                    .iter()
+                    .filter(|node| node.matches_name(&multiname))
+                    .map(|node| E4XOrXml::E4X(*node)),
+            );
         }
     }
     Ok(XmlListObject::new(activation, sub_children, Some(list.into())).into())
@@ -265,6 +313,24 @@ pub fn name<'gc>(
     }
 }
 
+pub fn node_kind<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let list = this.as_xml_list_object().unwrap();
+
+    let children = list.children();
+    match &children[..] {
+        [child] => Ok(child.node().node_kind().into()),
+        _ => Err(Error::AvmError(type_error(
+            activation,
+            "Error #1086: The nodeKind method only works on lists containing one item.",
+            1086,
+        )?)),
+    }
+}
+
 pub fn descendants<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -298,3 +364,50 @@ pub fn text<'gc>(
     }
     Ok(XmlListObject::new(activation, nodes, Some(xml_list.into())).into())
 }
+
+pub fn normalize<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let xml_list = this.as_xml_list_object().unwrap();
+    let mc = activation.context.gc_context;
+
+    // Like deleting an entry of the list, this also removes the node from its parent.
+    let remove_entry = |children: &mut Vec<E4XOrXml<'gc>>, index: usize| {
+        let removed = *children.remove(index).node();
+        if let Some(parent) = removed.parent() {
+            parent.remove_child(mc, &removed);
+        }
+    };
+
+    // Same as `E4XNode::normalize`, but for the entries of the list itself.
+    let mut children = xml_list.children_mut(mc);
+    let mut index = 0;
+    while index < children.len() {
+        let node = *children[index].node();
+        let Some(mut text) = node.text_content() else {
+            node.normalize(mc);
+            index += 1;
+            continue;
+        };
+
+        while let Some(next) = children
+            .get(index + 1)
+            .and_then(|next| next.node().text_content())
+        {
+            text = AvmString::concat(mc, text, next);
+            remove_entry(&mut children, index + 1);
+        }
+
+        if text.is_empty() {
+            remove_entry(&mut children, index);
+        } else {
+            node.set_text_content(mc, text);
+            index += 1;
+        }
+    }
+    drop(children);
+
+    Ok(xml_list.into())
+}
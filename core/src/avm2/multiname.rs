@@ -121,8 +121,8 @@ impl<'gc> Multiname<'gc> {
         context: &mut GcContext<'_, 'gc>,
     ) -> Result<NamespaceSet<'gc>, Error<'gc>> {
         if namespace_set_index.0 == 0 {
-            return Err(Error::RustError(
-                "Multiname namespace set must not be null".into(),
+            return Err(Error::rust_error(
+                "Multiname namespace set must not be null",
             ));
         }
 
@@ -253,8 +253,12 @@ impl<'gc> Multiname<'gc> {
                 if self.has_lazy_ns() {
                     let _ = activation.pop_stack(); // ignore the ns component
                 }
-                let name = qname_object.name();
-                return Ok(name.clone());
+                let mut name = qname_object.name().clone();
+                // `x.@[qname]` must still be an attribute lookup.
+                if self.is_attribute() {
+                    name.set_is_attribute(true);
+                }
+                return Ok(name);
             }
 
             Some(name_value.coerce_to_string(activation)?)
@@ -349,6 +353,14 @@ impl<'gc> Multiname<'gc> {
         }
     }
 
+    /// The namespace of this multiname, if it has an explicit one.
+    pub fn explicit_namespace(&self) -> Option<Namespace<'gc>> {
+        match self.ns {
+            NamespaceSet::Single(ns) if self.has_explicit_namespace() => Some(ns),
+            _ => None,
+        }
+    }
+
     /// Indicates if this multiname matches any type.
     pub fn is_any_name(&self) -> bool {
         self.name.is_none()
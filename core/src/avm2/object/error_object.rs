@@ -71,9 +71,9 @@ impl<'gc> ErrorObject<'gc> {
             Value::Null => "null".into(),
             Value::Undefined => "undefined".into(),
             name => {
-                return Err(Error::RustError(
-                    format!("Error.name {name:?} is not a string on error object {self:?}",).into(),
-                ))
+                return Err(Error::rust_error(format!(
+                    "Error.name {name:?} is not a string on error object {self:?}",
+                )))
             }
         };
         let message = match self.base().get_slot(2)? {
@@ -81,10 +81,9 @@ impl<'gc> ErrorObject<'gc> {
             Value::Null => "null".into(),
             Value::Undefined => "undefined".into(),
             message => {
-                return Err(Error::RustError(
-                    format!("Error.message {message:?} is not a string on error object {self:?}")
-                        .into(),
-                ))
+                return Err(Error::rust_error(format!(
+                    "Error.message {message:?} is not a string on error object {self:?}"
+                )))
             }
         };
         if message.is_empty() {
@@ -106,6 +106,16 @@ impl<'gc> SocketObject<'gc> {
         self.0.write_buffer.borrow_mut()
     }
 
+    /// The number of received bytes that have not been read yet.
+    pub fn bytes_available(&self) -> usize {
+        self.0.read_buffer.borrow().len()
+    }
+
+    /// The number of written bytes that have not been flushed yet.
+    pub fn bytes_pending(&self) -> usize {
+        self.0.write_buffer.borrow().len()
+    }
+
     pub fn read_bytes(&self, amnt: usize) -> Result<Vec<u8>, EofError> {
         let mut buf = self.read_buffer();
 
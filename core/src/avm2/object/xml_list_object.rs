@@ -1,7 +1,8 @@
 use crate::avm2::activation::Activation;
-use crate::avm2::e4x::{E4XNode, E4XNodeKind};
+use crate::avm2::e4x::{name_to_multiname, E4XNode, E4XNodeKind};
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{Object, ObjectPtr, TObject};
+use crate::avm2::string::AvmString;
 use crate::avm2::value::Value;
 use crate::avm2::{Error, Multiname};
 use gc_arena::{Collect, GcCell, GcWeakCell, MutationContext};
@@ -150,7 +151,13 @@ impl<'gc> XmlListObject<'gc> {
             let mut out = vec![];
             out.extend(left.children().clone());
             out.extend(right.children().clone());
-            Self::new(activation, out, None)
+            // Only keep the target if both lists were created from the same object,
+            // since modifications could otherwise only be reflected in one of them.
+            let target = match (left.target(), right.target()) {
+                (Some(left), Some(right)) if Object::ptr_eq(left, right) => Some(left),
+                _ => None,
+            };
+            Self::new(activation, out, target)
         }
     }
 }
@@ -249,45 +256,39 @@ impl<'gc> TObject<'gc> for XmlListObject<'gc> {
         // FIXME - implement everything from E4X spec (XMLListObject::getMultinameProperty in avmplus)
         let mut write = self.0.write(activation.context.gc_context);
 
-        if !name.has_explicit_namespace() {
-            if let Some(local_name) = name.local_name() {
-                if let Ok(index) = local_name.parse::<usize>() {
-                    if let Some(child) = write.children.get_mut(index) {
-                        return Ok(Value::Object(child.get_or_create_xml(activation).into()));
-                    } else {
-                        return Ok(Value::Undefined);
-                    }
+        if let Some(local_name) = name.local_name().filter(|_| !name.is_attribute()) {
+            if let Ok(index) = local_name.parse::<usize>() {
+                if let Some(child) = write.children.get_mut(index) {
+                    return Ok(Value::Object(child.get_or_create_xml(activation).into()));
+                } else {
+                    return Ok(Value::Undefined);
                 }
             }
-
-            let matched_children = write
-                .children
-                .iter_mut()
-                .flat_map(|child| {
-                    let child_prop = child
-                        .get_or_create_xml(activation)
-                        .get_property_local(name, activation)
-                        .unwrap();
-                    if let Some(prop_xml) =
-                        child_prop.as_object().and_then(|obj| obj.as_xml_object())
-                    {
-                        vec![E4XOrXml::Xml(prop_xml)]
-                    } else if let Some(prop_xml_list) = child_prop
-                        .as_object()
-                        .and_then(|obj| obj.as_xml_list_object())
-                    {
-                        // Flatten children
-                        prop_xml_list.children().clone()
-                    } else {
-                        vec![]
-                    }
-                })
-                .collect();
-
-            return Ok(XmlListObject::new(activation, matched_children, Some(self.into())).into());
         }
 
-        write.base.get_property_local(name, activation)
+        let matched_children = write
+            .children
+            .iter_mut()
+            .flat_map(|child| {
+                let child_prop = child
+                    .get_or_create_xml(activation)
+                    .get_property_local(name, activation)
+                    .unwrap();
+                if let Some(prop_xml) = child_prop.as_object().and_then(|obj| obj.as_xml_object()) {
+                    vec![E4XOrXml::Xml(prop_xml)]
+                } else if let Some(prop_xml_list) = child_prop
+                    .as_object()
+                    .and_then(|obj| obj.as_xml_list_object())
+                {
+                    // Flatten children
+                    prop_xml_list.children().clone()
+                } else {
+                    vec![]
+                }
+            })
+            .collect();
+
+        Ok(XmlListObject::new(activation, matched_children, Some(self.into())).into())
     }
 
     fn call_property_local(
@@ -351,22 +352,52 @@ impl<'gc> TObject<'gc> for XmlListObject<'gc> {
         if !name.is_any_name() && !name.is_attribute() {
             if let Some(local_name) = name.local_name() {
                 if let Ok(index) = local_name.parse::<usize>() {
-                    if let Some(target) = write.target {
-                        return Err(format!(
-                            "Modifying an XMLList object is not yet implemented: target {:?}",
-                            target
-                        )
-                        .into());
-                    }
-
-                    if index >= write.children.len() {
+                    let target = write.target.map(|target| target.as_xml_object());
+                    if index >= write.children.len() && !matches!(target, Some(None)) {
                         if let Some(value_xml) =
                             value.as_object().and_then(|obj| obj.as_xml_object())
                         {
+                            // Appending to a list created from an XML object also inserts the
+                            // value into that object, right after the last node of this list.
+                            if let Some(Some(target)) = target {
+                                let target_node = *target.node();
+                                let value_node = *value_xml.node();
+                                let insert_index = match &*target_node.kind() {
+                                    E4XNodeKind::Element { children, .. } => Some(
+                                        write
+                                            .children
+                                            .last()
+                                            .and_then(|last| {
+                                                children.iter().position(|child| {
+                                                    E4XNode::ptr_eq(*child, *last.node())
+                                                })
+                                            })
+                                            .map_or(children.len(), |index| index + 1),
+                                    ),
+                                    // Only elements have children to insert into.
+                                    _ => None,
+                                };
+                                if let Some(insert_index) = insert_index {
+                                    target_node.check_tree_size(activation, &[], &[value_node])?;
+                                    target_node.insert_child(
+                                        activation.context.gc_context,
+                                        insert_index,
+                                        value_node,
+                                    )?;
+                                }
+                            }
                             write.children.push(E4XOrXml::Xml(value_xml));
                             return Ok(());
                         }
                     }
+
+                    if let Some(target) = write.target {
+                        return Err(format!(
+                            "Modifying an XMLList object is not yet implemented: target {:?}",
+                            target
+                        )
+                        .into());
+                    }
                 }
             }
         }
@@ -383,6 +414,53 @@ impl<'gc> TObject<'gc> for XmlListObject<'gc> {
         .into())
     }
 
+    fn has_own_property(self, name: &Multiname<'gc>) -> bool {
+        let read = self.0.read();
+
+        // FIXME - see if we can deduplicate this with get_property_local in
+        // an efficient way
+        if let Some(local_name) = name.local_name() {
+            if !name.is_attribute() {
+                if let Ok(index) = local_name.parse::<usize>() {
+                    return index < read.children.len();
+                }
+            }
+
+            // We have the property if any of our element children has it
+            let any_child_has = read.children.iter().any(|child| {
+                if let E4XNodeKind::Element {
+                    children,
+                    attributes,
+                } = &*child.node().kind()
+                {
+                    let search_children = if name.is_attribute() {
+                        attributes
+                    } else {
+                        children
+                    };
+
+                    search_children.iter().any(|child| child.matches_name(name))
+                } else {
+                    false
+                }
+            });
+
+            if any_child_has {
+                return true;
+            }
+        }
+        read.base.has_own_dynamic_property(name)
+    }
+
+    fn has_own_property_string(
+        self,
+        name: impl Into<AvmString<'gc>>,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<bool, Error<'gc>> {
+        let name = name_to_multiname(activation, &Value::String(name.into()), false)?;
+        Ok(self.has_own_property(&name))
+    }
+
     fn get_next_enumerant(
         self,
         last_index: u32,
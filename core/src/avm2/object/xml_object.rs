@@ -171,9 +171,10 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
         // FIXME - implement everything from E4X spec (XMLObject::getMultinameProperty in avmplus)
         let read = self.0.read();
 
-        if !name.has_explicit_namespace() {
-            if let Some(local_name) = name.local_name() {
-                // The only supported numerical index is 0
+        if let Some(local_name) = name.local_name() {
+            // The only supported numerical index is 0.
+            // Attribute names are never indices, even when they are computed at runtime.
+            if !name.is_attribute() {
                 if let Ok(index) = local_name.parse::<usize>() {
                     if index == 0 {
                         return Ok(self.into());
@@ -182,36 +183,34 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
                     }
                 }
             }
+        }
 
-            let matched_children = if let E4XNodeKind::Element {
-                children,
-                attributes,
-            } = &*read.node.kind()
-            {
-                let search_children = if name.is_attribute() {
-                    attributes
-                } else {
-                    children
-                };
-
-                search_children
-                    .iter()
-                    .filter_map(|child| {
-                        if child.matches_name(name) {
-                            Some(E4XOrXml::E4X(*child))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<Vec<_>>()
+        let matched_children = if let E4XNodeKind::Element {
+            children,
+            attributes,
+        } = &*read.node.kind()
+        {
+            let search_children = if name.is_attribute() {
+                attributes
             } else {
-                Vec::new()
+                children
             };
 
-            return Ok(XmlListObject::new(activation, matched_children, Some(self.into())).into());
-        }
+            search_children
+                .iter()
+                .filter_map(|child| {
+                    if child.matches_name(name) {
+                        Some(E4XOrXml::E4X(*child))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
 
-        read.base.get_property_local(name, activation)
+        Ok(XmlListObject::new(activation, matched_children, Some(self.into())).into())
     }
 
     fn call_property_local(
@@ -258,26 +257,26 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
 
         // FIXME - see if we can deduplicate this with get_property_local in
         // an efficient way
-        if !name.has_explicit_namespace() {
-            if let Some(local_name) = name.local_name() {
-                // The only supported numerical index is 0
+        if let Some(local_name) = name.local_name() {
+            // The only supported numerical index is 0
+            if !name.is_attribute() {
                 if let Ok(index) = local_name.parse::<usize>() {
                     return index == 0;
                 }
+            }
 
-                if let E4XNodeKind::Element {
-                    children,
-                    attributes,
-                } = &*read.node.kind()
-                {
-                    let search_children = if name.is_attribute() {
-                        attributes
-                    } else {
-                        children
-                    };
+            if let E4XNodeKind::Element {
+                children,
+                attributes,
+            } = &*read.node.kind()
+            {
+                let search_children = if name.is_attribute() {
+                    attributes
+                } else {
+                    children
+                };
 
-                    return search_children.iter().any(|child| child.matches_name(name));
-                }
+                return search_children.iter().any(|child| child.matches_name(name));
             }
         }
         read.base.has_own_dynamic_property(name)
@@ -298,7 +297,7 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
         value: Value<'gc>,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<(), Error<'gc>> {
-        if name.has_explicit_namespace() {
+        if name.has_explicit_namespace() && !name.is_attribute() {
             return Err(format!(
                 "Can not set property {:?} with an explicit namespace yet",
                 name
@@ -323,24 +322,22 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
                 return Err(format!("Cannot set attribute {:?} without a local name", name).into());
             };
             let value = value.coerce_to_string(activation)?;
-            let new_attr = E4XNode::attribute(mc, local_name, value, *self.node());
-
-            let write = self.0.write(mc);
-            let mut kind = write.node.kind_mut(mc);
-            let E4XNodeKind::Element { attributes, .. } = &mut *kind else {
-                return Ok(());
-            };
-
-            attributes.push(new_attr);
+            let namespace = name
+                .explicit_namespace()
+                .map(|ns| ns.as_uri())
+                .filter(|uri| !uri.is_empty());
+            let new_attr = E4XNode::attribute(mc, local_name, namespace, value, *self.node());
+
+            let node = *self.node();
+            node.check_tree_growth(activation, 0, 1)?;
+            node.append_attribute(mc, new_attr);
             return Ok(());
         }
 
         let self_node = *self.node();
-        let write = self.0.write(mc);
-        let mut kind = write.node.kind_mut(mc);
-        let E4XNodeKind::Element { children, .. } = &mut *kind else {
+        if !matches!(&*self_node.kind(), E4XNodeKind::Element { .. }) {
             return Ok(());
-        };
+        }
 
         if value.as_object().map_or(false, |obj| {
             obj.as_xml_object().is_some() || obj.as_xml_list_object().is_some()
@@ -356,22 +353,31 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
 
         let text = value.coerce_to_string(activation)?;
 
-        let matches: Vec<_> = children
-            .iter()
-            .filter(|child| child.matches_name(name))
-            .collect();
+        let matches: Vec<_> = match &*self_node.kind() {
+            E4XNodeKind::Element { children, .. } => children
+                .iter()
+                .filter(|child| child.matches_name(name))
+                .copied()
+                .collect(),
+            _ => Vec::new(),
+        };
         match matches.as_slice() {
             [] => {
-                let element_with_text =
-                    E4XNode::element(mc, name.local_name().unwrap(), write.node);
-                element_with_text.append_child(mc, E4XNode::text(mc, text, Some(self_node)))?;
-                children.push(element_with_text);
-                Ok(())
+                // The new element and its text.
+                self_node.check_tree_growth(activation, 0, 2)?;
+                let element_with_text = E4XNode::element(mc, name.local_name().unwrap(), None);
+                element_with_text.append_child(mc, E4XNode::text(mc, text, None))?;
+                self_node.append_child(mc, element_with_text)
             }
             [child] => {
+                let old_children = match &*child.kind() {
+                    E4XNodeKind::Element { children, .. } => children.clone(),
+                    _ => Vec::new(),
+                };
+                let text = E4XNode::text(mc, text, None);
+                self_node.check_tree_size(activation, &old_children, &[text])?;
                 child.remove_all_children(mc);
-                child.append_child(mc, E4XNode::text(mc, text, Some(self_node)))?;
-                Ok(())
+                child.append_child(mc, text)
             }
             _ => Err(format!("Can not replace multiple elements yet: {name:?} = {value:?}").into()),
         }
@@ -382,40 +388,35 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
         activation: &mut Activation<'_, 'gc>,
         name: &Multiname<'gc>,
     ) -> Result<bool, Error<'gc>> {
-        if name.has_explicit_namespace() {
-            return Err(format!(
-                "Can not set property {:?} with an explicit namespace yet",
-                name
-            )
-            .into());
-        }
-
         let mc = activation.context.gc_context;
-        let write = self.0.write(mc);
-        let mut kind = write.node.kind_mut(mc);
-        let E4XNodeKind::Element {
-            children,
-            attributes,
-            ..
-        } = &mut *kind
-        else {
-            return Ok(false);
+        let node = *self.node();
+        let removed: Vec<_> = match &*node.kind() {
+            E4XNodeKind::Element {
+                children,
+                attributes,
+            } => {
+                let search_children = if name.is_attribute() {
+                    attributes
+                } else {
+                    children
+                };
+                search_children
+                    .iter()
+                    .filter(|child| child.matches_name(name))
+                    .copied()
+                    .collect()
+            }
+            _ => return Ok(false),
         };
 
-        let retain_non_matching = |node: &E4XNode<'gc>| {
-            if node.matches_name(name) {
-                node.set_parent(None, mc);
-                false
+        for child in removed {
+            if name.is_attribute() {
+                node.remove_attribute(mc, &child);
             } else {
-                true
+                node.remove_child(mc, &child);
             }
-        };
-
-        if name.is_attribute() {
-            attributes.retain(retain_non_matching);
-        } else {
-            children.retain(retain_non_matching);
         }
+
         Ok(true)
     }
 }
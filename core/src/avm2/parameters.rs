@@ -1,4 +1,4 @@
-use crate::avm2::error::type_error;
+use crate::avm2::error::make_error_2007;
 use crate::avm2::object::PrimitiveObject;
 use crate::avm2::Object;
 use crate::avm2::{Activation, Error, Value};
@@ -101,7 +101,7 @@ impl<'gc> ParametersExt<'gc> for &[Value<'gc>] {
         name: &'static str,
     ) -> Result<Object<'gc>, Error<'gc>> {
         match self[index] {
-            Value::Null | Value::Undefined => Err(null_parameter_error(activation, name)),
+            Value::Null | Value::Undefined => Err(make_error_2007(activation, name)),
             Value::Object(o) => Ok(o),
             primitive => Ok(PrimitiveObject::from_primitive(primitive, activation)
                 .expect("Primitive object is infallible at this point")),
@@ -170,15 +170,3 @@ impl<'gc> ParametersExt<'gc> for &[Value<'gc>] {
         }
     }
 }
-
-pub fn null_parameter_error<'gc>(activation: &mut Activation<'_, 'gc>, name: &str) -> Error<'gc> {
-    let error = type_error(
-        activation,
-        &format!("Error #2007: Parameter {name} must be non-null."),
-        2007,
-    );
-    match error {
-        Err(e) => e,
-        Ok(e) => Error::AvmError(e),
-    }
-}
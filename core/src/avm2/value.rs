@@ -965,9 +965,10 @@ impl<'gc> Value<'gc> {
             .domain()
             .get_class(type_name, activation.context.gc_context)?
             .ok_or_else(|| {
-                Error::RustError(
-                    format!("Failed to lookup class {:?} during coercion", type_name).into(),
-                )
+                Error::rust_error(format!(
+                    "Failed to lookup class {:?} during coercion",
+                    type_name
+                ))
             })?;
 
         self.coerce_to_type(activation, param_type)
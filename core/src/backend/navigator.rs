@@ -108,6 +108,11 @@ pub struct Request {
     /// to give us a consistent order - hopefully, no servers depend on
     /// the order of headers.
     headers: IndexMap<String, String>,
+
+    /// Where to report the download progress of the response body, if anywhere.
+    ///
+    /// Backends that can't observe the body as it arrives may ignore this.
+    progress: Option<Sender<FetchProgress>>,
 }
 
 impl Request {
@@ -118,6 +123,7 @@ impl Request {
             method: NavigationMethod::Get,
             body: None,
             headers: Default::default(),
+            progress: None,
         }
     }
 
@@ -128,6 +134,7 @@ impl Request {
             method: NavigationMethod::Post,
             body,
             headers: Default::default(),
+            progress: None,
         }
     }
 
@@ -139,6 +146,7 @@ impl Request {
             method,
             body,
             headers: Default::default(),
+            progress: None,
         }
     }
 
@@ -168,6 +176,25 @@ impl Request {
     pub fn set_headers(&mut self, headers: IndexMap<String, String>) {
         self.headers = headers;
     }
+
+    /// Retrieve the sink for download progress updates of this request, if it has one.
+    pub fn progress_sender(&self) -> Option<&Sender<FetchProgress>> {
+        self.progress.as_ref()
+    }
+
+    pub fn set_progress_sender(&mut self, sender: Sender<FetchProgress>) {
+        self.progress = Some(sender);
+    }
+}
+
+/// The download progress of a fetch request.
+#[derive(Clone, Copy, Debug)]
+pub struct FetchProgress {
+    /// The number of bytes of the response body received so far.
+    pub bytes_loaded: u64,
+
+    /// The total size of the response body in bytes, if known.
+    pub bytes_total: Option<u64>,
 }
 
 /// A response to a successful fetch request.
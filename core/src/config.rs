@@ -25,8 +25,9 @@ pub enum Letterbox {
 }
 
 /// The networking API access mode of the Ruffle player.
-/// This setting is only used on web.
-#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// On desktop, this setting only controls socket connections.
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum NetworkingAccessMode {
     /// All networking APIs are permitted in the SWF file.
     #[serde(rename = "all")]
@@ -3,6 +3,8 @@ mod avm2;
 mod display_object;
 mod handle;
 mod movie;
+mod render_info;
+mod socket;
 
 use crate::context::{RenderContext, UpdateContext};
 use crate::debug_ui::avm1::Avm1ObjectWindow;
@@ -10,6 +12,8 @@ use crate::debug_ui::avm2::Avm2ObjectWindow;
 use crate::debug_ui::display_object::{DisplayObjectSearchWindow, DisplayObjectWindow};
 use crate::debug_ui::handle::{AVM1ObjectHandle, AVM2ObjectHandle, DisplayObjectHandle};
 use crate::debug_ui::movie::{MovieListWindow, MovieWindow};
+use crate::debug_ui::render_info::RenderInfoWindow;
+use crate::debug_ui::socket::SocketListWindow;
 use crate::display_object::TDisplayObject;
 use crate::tag_utils::SwfMovie;
 use gc_arena::DynamicRootSet;
@@ -31,6 +35,8 @@ pub struct DebugUi {
     items_to_save: Vec<ItemToSave>,
     movie_list: Option<MovieListWindow>,
     display_object_search: Option<DisplayObjectSearchWindow>,
+    socket_list: Option<SocketListWindow>,
+    render_info: Option<RenderInfoWindow>,
 }
 
 #[derive(Debug)]
@@ -44,6 +50,8 @@ pub enum Message {
     ShowKnownMovies,
     SaveFile(ItemToSave),
     SearchForDisplayObject,
+    ShowSockets,
+    ShowRenderInfo,
 }
 
 impl DebugUi {
@@ -79,6 +87,18 @@ impl DebugUi {
             }
         }
 
+        if let Some(mut socket_list) = self.socket_list.take() {
+            if socket_list.show(egui_ctx, context) {
+                self.socket_list = Some(socket_list);
+            }
+        }
+
+        if let Some(mut render_info) = self.render_info.take() {
+            if render_info.show(egui_ctx, context) {
+                self.render_info = Some(render_info);
+            }
+        }
+
         if let Some(mut search) = self.display_object_search.take() {
             if search.show(egui_ctx, context, &mut messages, movie_offset) {
                 self.display_object_search = Some(search);
@@ -114,6 +134,12 @@ impl DebugUi {
                 Message::SearchForDisplayObject => {
                     self.display_object_search = Some(Default::default());
                 }
+                Message::ShowSockets => {
+                    self.socket_list = Some(Default::default());
+                }
+                Message::ShowRenderInfo => {
+                    self.render_info = Some(Default::default());
+                }
             }
         }
     }
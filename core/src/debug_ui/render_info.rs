@@ -0,0 +1,27 @@
+use crate::context::UpdateContext;
+use egui::Window;
+
+#[derive(Debug, Default)]
+pub struct RenderInfoWindow {}
+
+impl RenderInfoWindow {
+    pub fn show(&mut self, egui_ctx: &egui::Context, context: &mut UpdateContext) -> bool {
+        let mut keep_open = true;
+
+        Window::new("Render Info")
+            .open(&mut keep_open)
+            .scroll2([true, true])
+            .show(egui_ctx, |ui| {
+                let info = context.renderer.debug_info();
+                if ui.button("Copy to clipboard").clicked() {
+                    ui.output_mut(|output| output.copied_text = info.to_string());
+                }
+                ui.separator();
+
+                for line in info.lines() {
+                    ui.label(line);
+                }
+            });
+        keep_open
+    }
+}
@@ -0,0 +1,68 @@
+use crate::avm2::TObject;
+use crate::context::UpdateContext;
+use crate::socket::ConnectionState;
+use egui::{Grid, Window};
+
+#[derive(Debug, Default)]
+pub struct SocketListWindow {}
+
+impl SocketListWindow {
+    pub fn show(&mut self, egui_ctx: &egui::Context, context: &mut UpdateContext) -> bool {
+        let mut keep_open = true;
+
+        Window::new("Socket List")
+            .open(&mut keep_open)
+            .scroll2([true, true])
+            .show(egui_ctx, |ui| {
+                let stats = context.sockets.stats();
+                ui.label(format!(
+                    "Connection attempts: {} ({} connected, {} failed, {} timed out)",
+                    stats.attempts, stats.successes, stats.failures, stats.timeouts
+                ));
+                ui.separator();
+
+                let mut to_close = None;
+                Grid::new("socket_list").num_columns(9).show(ui, |ui| {
+                    ui.strong("Handle");
+                    ui.strong("Kind");
+                    ui.strong("State");
+                    ui.strong("Sent");
+                    ui.strong("Received");
+                    ui.strong("Unread");
+                    ui.strong("Unflushed");
+                    ui.strong("Label");
+                    ui.end_row();
+
+                    for (handle, socket) in context.sockets.iter() {
+                        ui.label(handle.into_raw_parts().0.to_string());
+                        ui.label(
+                            socket
+                                .target()
+                                .instance_of_class_name(context.gc_context)
+                                .to_string(),
+                        );
+                        ui.label(match socket.state() {
+                            None => "Connecting",
+                            Some(ConnectionState::Connected) => "Connected",
+                            Some(ConnectionState::Failed) => "Failed",
+                            Some(ConnectionState::TimedOut) => "Timed out",
+                        });
+                        ui.label(format!("{} B", socket.bytes_sent()));
+                        ui.label(format!("{} B", socket.bytes_received()));
+                        ui.label(format!("{} B", socket.target().bytes_available()));
+                        ui.label(format!("{} B", socket.target().bytes_pending()));
+                        ui.label(socket.label());
+                        if ui.button("Close").clicked() {
+                            to_close = Some(handle);
+                        }
+                        ui.end_row();
+                    }
+                });
+
+                if let Some(handle) = to_close {
+                    context.sockets.close(handle);
+                }
+            });
+        keep_open
+    }
+}
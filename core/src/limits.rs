@@ -91,3 +91,11 @@ impl ExecutionLimit {
         false
     }
 }
+
+/// The default maximum number of nodes (including attributes) that a single E4X
+/// `XML` tree may contain. This can be changed with `PlayerBuilder::with_max_e4x_nodes`.
+///
+/// This is enforced when parsing XML source and when content grows a tree
+/// with `appendChild`, `prependChild` or `setChildren`, so that content cannot
+/// exhaust memory by building enormous documents.
+pub const MAX_E4X_NODES: usize = 1_000_000;
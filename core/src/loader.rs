@@ -12,7 +12,7 @@ use crate::avm2::{
     Activation as Avm2Activation, Avm2, Domain as Avm2Domain, Object as Avm2Object,
     Value as Avm2Value,
 };
-use crate::backend::navigator::{OwnedFuture, Request};
+use crate::backend::navigator::{FetchProgress, OwnedFuture, Request};
 use crate::bitmap::bitmap_data::Color;
 use crate::bitmap::bitmap_data::{BitmapData, BitmapDataWrapper};
 use crate::context::{ActionQueue, ActionType, UpdateContext};
@@ -32,6 +32,7 @@ use gc_arena::{Collect, GcCell};
 use generational_arena::{Arena, Index};
 use ruffle_render::utils::{determine_jpeg_tag_format, JpegTagFormat};
 use std::fmt;
+use std::sync::mpsc::{channel, Receiver};
 use std::sync::{Arc, Mutex, Weak};
 use std::time::Duration;
 use swf::read::{extract_swz, read_compression_type};
@@ -157,7 +158,7 @@ pub enum Error {
     NotMovieUnloader,
 
     #[error("HTTP Status is not OK: {0} redirected: {1}")]
-    HttpNotOk(String, u16, bool),
+    HttpNotOk(String, u16, bool, Vec<u8>),
 
     #[error("Could not fetch: {0}")]
     FetchError(String),
@@ -364,18 +365,71 @@ impl<'gc> LoadManager<'gc> {
         &mut self,
         player: Weak<Mutex<Player>>,
         target_object: Avm2Object<'gc>,
-        request: Request,
+        mut request: Request,
         data_format: DataFormat,
     ) -> OwnedFuture<(), Error> {
+        let (progress_sender, progress) = channel();
+        request.set_progress_sender(progress_sender);
         let loader = Loader::LoadURLLoader {
             self_handle: None,
             target_object,
+            progress: Some(progress),
+            opened: false,
         };
         let handle = self.add_loader(loader);
         let loader = self.get_loader_mut(handle).unwrap();
         loader.load_url_loader(player, request, data_format)
     }
 
+    /// Dispatches `progress` events to the `URLLoader`s that received data since the
+    /// last call, preceded by an `open` event for the first data a loader receives.
+    pub fn update_url_loader_progress(context: &mut UpdateContext<'_, 'gc>) {
+        let mut updates = vec![];
+        for (_, loader) in context.load_manager.0.iter_mut() {
+            if let Loader::LoadURLLoader {
+                target_object,
+                progress: Some(progress),
+                opened,
+                ..
+            } = loader
+            {
+                if let Some(latest) = progress.try_iter().last() {
+                    updates.push((*target_object, latest, !*opened));
+                    *opened = true;
+                }
+            }
+        }
+
+        for (target, progress, first_update) in updates {
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            if first_update {
+                let open_evt = Avm2EventObject::bare_default_event(&mut activation.context, "open");
+                Avm2::dispatch_event(&mut activation.context, open_evt, target);
+            }
+
+            let progress_evt = activation.avm2().classes().progressevent.construct(
+                &mut activation,
+                &[
+                    "progress".into(),
+                    false.into(),
+                    false.into(),
+                    (progress.bytes_loaded as f64).into(),
+                    (progress.bytes_total.unwrap_or(0) as f64).into(),
+                ],
+            );
+
+            match progress_evt {
+                Err(e) => tracing::error!(
+                    "Encountered AVM2 error when constructing `progress` event: {}",
+                    e,
+                ),
+                Ok(progress_evt) => {
+                    Avm2::dispatch_event(&mut activation.context, progress_evt, target);
+                }
+            }
+        }
+    }
+
     /// Kick off an AVM1 audio load.
     ///
     /// Returns the loader's async process, which you will need to spawn.
@@ -560,6 +614,14 @@ pub enum Loader<'gc> {
 
         /// The target `URLLoader` to load data into.
         target_object: Avm2Object<'gc>,
+
+        /// Download progress reported by the navigator while the data is loading.
+        #[collect(require_static)]
+        progress: Option<Receiver<FetchProgress>>,
+
+        /// Whether the `open` event was already fired, which happens when the first
+        /// download progress is reported.
+        opened: bool,
     },
 
     /// Loader that is loading an MP3 into an AVM1 Sound object.
@@ -795,7 +857,8 @@ impl<'gc> Loader<'gc> {
                         // FIXME - match Flash's error message
 
                         let (status_code, redirected) =
-                            if let Error::HttpNotOk(_, status_code, redirected) = response.error {
+                            if let Error::HttpNotOk(_, status_code, redirected, _) = response.error
+                            {
                                 (status_code, redirected)
                             } else {
                                 (0, false)
@@ -1002,7 +1065,7 @@ impl<'gc> Loader<'gc> {
                         // TODO: Log "Error opening URL" trace similar to the Flash Player?
 
                         let status_code =
-                            if let Error::HttpNotOk(_, status_code, _) = response.error {
+                            if let Error::HttpNotOk(_, status_code, _, _) = response.error {
                                 status_code
                             } else {
                                 0
@@ -1053,9 +1116,19 @@ impl<'gc> Loader<'gc> {
             let response = fetch.await;
 
             player.lock().unwrap().update(|uc| {
-                let loader = uc.load_manager.get_loader(handle);
-                let target = match loader {
-                    Some(&Loader::LoadURLLoader { target_object, .. }) => target_object,
+                let loader = uc.load_manager.get_loader_mut(handle);
+                let (target, opened) = match loader {
+                    Some(Loader::LoadURLLoader {
+                        target_object,
+                        progress,
+                        opened,
+                        ..
+                    }) => {
+                        // Any progress that wasn't reported yet is superseded by the final
+                        // `progress` event below.
+                        *progress = None;
+                        (*target_object, *opened)
+                    }
                     // We would have already returned after the previous 'update' call
                     _ => unreachable!(),
                 };
@@ -1100,18 +1173,23 @@ impl<'gc> Loader<'gc> {
                         // we start to fetch the data.
                         // However, the "open" event should not be fired if an IO error
                         // occurs opening the connection (e.g. if a file does not exist on disk).
-                        // We currently have no way of detecting this, so we settle for firing
-                        // the event after the entire fetch is complete. This causes there
-                        // to a longer delay between the initial load triggered by the script
-                        // and the "load" event firing, but it ensures that we match
-                        // the Flash behavior w.r.t when an event is fired vs not fired.
-                        let open_evt =
-                            Avm2EventObject::bare_default_event(&mut activation.context, "open");
-                        Avm2::dispatch_event(&mut activation.context, open_evt, target);
+                        // Backends that report download progress let us fire it once the
+                        // first data arrives. Otherwise, we settle for firing the event after
+                        // the entire fetch is complete. This causes there to a longer delay
+                        // between the initial load triggered by the script and the "load"
+                        // event firing, but it ensures that we match the Flash behavior
+                        // w.r.t when an event is fired vs not fired.
+                        if !opened {
+                            let open_evt = Avm2EventObject::bare_default_event(
+                                &mut activation.context,
+                                "open",
+                            );
+                            Avm2::dispatch_event(&mut activation.context, open_evt, target);
+                        }
                         set_data(response.body, &mut activation, target, data_format);
 
-                        // FIXME - we should fire "progress" events as we receive data, not
-                        // just at the end
+                        // Backends that report download progress have already fired
+                        // "progress" events as the data arrived, this is the final one.
                         let progress_evt = activation
                             .avm2()
                             .classes()
@@ -1156,16 +1234,18 @@ impl<'gc> Loader<'gc> {
                     }
                     Err(response) => {
                         // Testing with Flash shoes that the 'data' property is cleared
-                        // when an error occurs
-
-                        set_data(Vec::new(), &mut activation, target, data_format);
-
-                        let (status_code, redirected) =
-                            if let Error::HttpNotOk(_, status_code, redirected) = response.error {
-                                (status_code, redirected)
+                        // when an error occurs. HTTP errors still expose the response
+                        // body, which may contain an error payload.
+                        let (status_code, redirected, body) =
+                            if let Error::HttpNotOk(_, status_code, redirected, body) =
+                                response.error
+                            {
+                                (status_code, redirected, body)
                             } else {
-                                (0, false)
+                                (0, false, Vec::new())
                             };
+
+                        set_data(body, &mut activation, target, data_format);
                         let http_status_evt = activation
                             .avm2()
                             .classes()
@@ -36,11 +36,11 @@ use crate::focus_tracker::FocusTracker;
 use crate::font::Font;
 use crate::frame_lifecycle::{run_all_phases_avm2, FramePhase};
 use crate::library::Library;
-use crate::limits::ExecutionLimit;
+use crate::limits::{ExecutionLimit, MAX_E4X_NODES};
 use crate::loader::{LoadBehavior, LoadManager};
 use crate::locale::get_current_date_time;
 use crate::prelude::*;
-use crate::socket::Sockets;
+use crate::socket::{SocketStats, Sockets};
 use crate::streams::StreamManager;
 use crate::string::{AvmString, AvmStringInterner};
 use crate::stub::StubCollection;
@@ -554,6 +554,7 @@ impl Player {
             self.update_timers(dt);
             self.update(|context| {
                 StreamManager::tick(context, dt);
+                LoadManager::update_url_loader_progress(context);
             });
             self.audio.tick();
         }
@@ -837,12 +838,31 @@ impl Player {
         })
     }
 
+    pub fn quality(&mut self) -> StageQuality {
+        self.mutate_with_update_context(|context| context.stage.quality())
+    }
+
     pub fn set_quality(&mut self, quality: StageQuality) {
         self.mutate_with_update_context(|context| {
             context.stage.set_quality(context, quality);
         })
     }
 
+    pub fn scale_mode(&mut self) -> StageScaleMode {
+        self.mutate_with_update_context(|context| context.stage.scale_mode())
+    }
+
+    /// Sets the stage scale mode, even if movies are prevented from changing it.
+    pub fn set_scale_mode(&mut self, scale_mode: StageScaleMode) {
+        self.mutate_with_update_context(|context| {
+            let stage = context.stage;
+            let forced = stage.forced_scale_mode();
+            stage.set_forced_scale_mode(context, false);
+            stage.set_scale_mode(context, scale_mode);
+            stage.set_forced_scale_mode(context, forced);
+        })
+    }
+
     pub fn set_window_mode(&mut self, window_mode: &str) {
         self.mutate_with_update_context(|context| {
             let stage = context.stage;
@@ -1568,6 +1588,19 @@ impl Player {
         })
     }
 
+    /// Runs exactly one frame while the player is suspended, for stepping through a movie.
+    ///
+    /// Does nothing if the player is playing. Timers, sockets and streams don't advance.
+    pub fn step_frame(&mut self) {
+        if self.is_playing() || !self.audio.is_loading_complete() {
+            return;
+        }
+
+        let timer = Instant::now();
+        self.run_frame();
+        self.add_frame_timing(timer.elapsed().as_millis() as f64);
+    }
+
     #[instrument(level = "debug", skip_all)]
     pub fn run_frame(&mut self) {
         let frame_time = Duration::from_nanos((750_000_000.0 / self.frame_rate) as u64);
@@ -2024,6 +2057,11 @@ impl Player {
         })
     }
 
+    /// Returns the socket connection attempt counters for this player.
+    pub fn socket_stats(&mut self) -> SocketStats {
+        self.mutate_with_update_context(|context| context.sockets.stats())
+    }
+
     /// Returns whether this player consumes mouse wheel events.
     /// Used by web to prevent scrolling.
     pub fn should_prevent_scrolling(&mut self) -> bool {
@@ -2099,6 +2137,7 @@ pub struct PlayerBuilder {
     fullscreen: bool,
     letterbox: Letterbox,
     max_execution_duration: Duration,
+    max_e4x_nodes: usize,
     viewport_width: u32,
     viewport_height: u32,
     viewport_scale_factor: f64,
@@ -2143,6 +2182,7 @@ impl PlayerBuilder {
             } else {
                 15
             }),
+            max_e4x_nodes: MAX_E4X_NODES,
             viewport_width: 550,
             viewport_height: 400,
             viewport_scale_factor: 1.0,
@@ -2242,6 +2282,13 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets the maximum number of nodes (including attributes) in an E4X `XML` tree.
+    #[inline]
+    pub fn with_max_e4x_nodes(mut self, max_e4x_nodes: usize) -> Self {
+        self.max_e4x_nodes = max_e4x_nodes;
+        self
+    }
+
     /// Sets the dimensions of the stage.
     #[inline]
     pub fn with_viewport_dimensions(
@@ -2321,6 +2368,7 @@ impl PlayerBuilder {
     fn create_gc_root<'gc>(
         gc_context: &'gc gc_arena::Mutation<'gc>,
         player_version: u8,
+        max_e4x_nodes: usize,
         fullscreen: bool,
         fake_movie: Arc<SwfMovie>,
         external_interface_providers: Vec<Box<dyn ExternalInterfaceProvider>>,
@@ -2331,6 +2379,8 @@ impl PlayerBuilder {
             interner: &mut interner,
         };
         let dynamic_root = DynamicRootSet::new(gc_context);
+        let mut avm2 = Avm2::new(&mut init, player_version);
+        avm2.set_max_e4x_nodes(max_e4x_nodes);
 
         GcRoot {
             callstack: GcCell::new(gc_context, GcCallstack::default()),
@@ -2340,7 +2390,7 @@ impl PlayerBuilder {
                     audio_manager: AudioManager::new(),
                     action_queue: ActionQueue::new(),
                     avm1: Avm1::new(&mut init, player_version),
-                    avm2: Avm2::new(&mut init, player_version),
+                    avm2,
                     interner,
                     current_context_menu: None,
                     drag_object: None,
@@ -2456,6 +2506,7 @@ impl PlayerBuilder {
                         Self::create_gc_root(
                             gc_context,
                             player_version,
+                            self.max_e4x_nodes,
                             self.fullscreen,
                             fake_movie.clone(),
                             self.external_interface_providers,
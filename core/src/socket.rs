@@ -16,23 +16,57 @@ pub type SocketHandle = Index;
 
 #[derive(Collect)]
 #[collect(no_drop)]
-struct Socket<'gc> {
+pub struct Socket<'gc> {
     target: SocketObject<'gc>,
     sender: RefCell<Sender<Vec<u8>>>,
     send_buffer: VecDeque<Vec<u8>>,
+    /// A human-readable label for this connection (its `host:port`), shown in debugging tools.
+    label: String,
+    /// The outcome of the connection attempt, or `None` while it is still pending.
+    #[collect(require_static)]
+    state: Option<ConnectionState>,
+    /// Total number of bytes queued for sending on this socket.
+    bytes_sent: u64,
+    /// Total number of bytes received on this socket.
+    bytes_received: u64,
 }
 
 impl<'gc> Socket<'gc> {
-    fn new(target: SocketObject<'gc>, sender: Sender<Vec<u8>>) -> Self {
+    fn new(target: SocketObject<'gc>, sender: Sender<Vec<u8>>, label: String) -> Self {
         Self {
             target,
             sender: RefCell::new(sender),
             send_buffer: Default::default(),
+            label,
+            state: None,
+            bytes_sent: 0,
+            bytes_received: 0,
         }
     }
+
+    pub fn target(&self) -> SocketObject<'gc> {
+        self.target
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The outcome of the connection attempt, or `None` while it is still pending.
+    pub fn state(&self) -> Option<ConnectionState> {
+        self.state
+    }
+
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent
+    }
+
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received
+    }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ConnectionState {
     Connected,
     Failed,
@@ -46,10 +80,21 @@ pub enum SocketAction {
     Close(SocketHandle),
 }
 
+/// Counts of socket connection attempts and their outcomes over the lifetime of a player.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SocketStats {
+    pub attempts: u64,
+    pub successes: u64,
+    pub failures: u64,
+    pub timeouts: u64,
+}
+
 /// Manages the collection of Sockets.
 pub struct Sockets<'gc> {
     sockets: Arena<Socket<'gc>>,
 
+    stats: SocketStats,
+
     receiver: Receiver<SocketAction>,
     sender: Sender<SocketAction>,
 }
@@ -68,6 +113,7 @@ impl<'gc> Sockets<'gc> {
 
         Self {
             sockets: Arena::new(),
+            stats: Default::default(),
             receiver,
             sender,
         }
@@ -82,8 +128,9 @@ impl<'gc> Sockets<'gc> {
     ) {
         let (sender, receiver) = channel();
 
-        let socket = Socket::new(target, sender);
+        let socket = Socket::new(target, sender, format!("{host}:{port}"));
         let handle = self.sockets.insert(socket);
+        self.stats.attempts += 1;
 
         // NOTE: This call will send SocketAction::Connect to sender with connection status.
         backend.connect_socket(
@@ -102,19 +149,59 @@ impl<'gc> Sockets<'gc> {
         }
     }
 
+    /// The connection attempt counters for this player.
+    pub fn stats(&self) -> SocketStats {
+        self.stats
+    }
+
+    /// Iterates over all currently open sockets.
+    pub fn iter(&self) -> impl Iterator<Item = (SocketHandle, &Socket<'gc>)> {
+        self.sockets.iter()
+    }
+
+    /// Whether the socket exists and has finished connecting successfully.
+    ///
+    /// Sockets that are still connecting are not connected yet, so data can't be
+    /// read from or written to them.
     pub fn is_connected(&self, handle: SocketHandle) -> bool {
-        matches!(self.sockets.get(handle), Some(Socket { .. }))
+        matches!(
+            self.sockets.get(handle),
+            Some(Socket {
+                state: Some(ConnectionState::Connected),
+                ..
+            })
+        )
     }
 
-    pub fn send(&mut self, handle: SocketHandle, data: Vec<u8>) {
-        if let Some(Socket { send_buffer, .. }) = self.sockets.get_mut(handle) {
+    /// Whether the socket exists and is still waiting for the result of its connection attempt.
+    pub fn is_connecting(&self, handle: SocketHandle) -> bool {
+        matches!(self.sockets.get(handle), Some(Socket { state: None, .. }))
+    }
+
+    /// Queues data to be sent on a socket.
+    ///
+    /// Returns `false` if the socket doesn't exist, e.g. because it was already closed.
+    #[must_use]
+    pub fn send(&mut self, handle: SocketHandle, data: Vec<u8>) -> bool {
+        if let Some(Socket {
+            send_buffer,
+            bytes_sent,
+            ..
+        }) = self.sockets.get_mut(handle)
+        {
+            *bytes_sent += data.len() as u64;
             send_buffer.push_back(data);
+            true
+        } else {
+            false
         }
     }
 
     pub fn close(&mut self, handle: SocketHandle) {
         if let Some(Socket { sender, .. }) = self.sockets.remove(handle) {
-            drop(sender); // NOTE: By dropping the sender, the reading task will close automatically.
+            // NOTE: By dropping the sender, the backend's writing task will flush any queued data
+            //       and then shut down the connection.
+            drop(sender);
         }
     }
 
@@ -130,8 +217,13 @@ impl<'gc> Sockets<'gc> {
         for action in actions {
             match action {
                 SocketAction::Connect(handle, ConnectionState::Connected) => {
-                    let target = match activation.context.sockets.sockets.get(handle) {
-                        Some(socket) => socket.target,
+                    activation.context.sockets.stats.successes += 1;
+
+                    let target = match activation.context.sockets.sockets.get_mut(handle) {
+                        Some(socket) => {
+                            socket.state = Some(ConnectionState::Connected);
+                            socket.target
+                        }
                         // Socket must have been closed before we could send event.
                         None => continue,
                     };
@@ -142,10 +234,20 @@ impl<'gc> Sockets<'gc> {
                 }
                 SocketAction::Connect(
                     handle,
-                    ConnectionState::Failed | ConnectionState::TimedOut,
+                    state @ (ConnectionState::Failed | ConnectionState::TimedOut),
                 ) => {
-                    let target = match activation.context.sockets.sockets.get(handle) {
-                        Some(socket) => socket.target,
+                    let stats = &mut activation.context.sockets.stats;
+                    if matches!(state, ConnectionState::TimedOut) {
+                        stats.timeouts += 1;
+                    } else {
+                        stats.failures += 1;
+                    }
+
+                    let target = match activation.context.sockets.sockets.get_mut(handle) {
+                        Some(socket) => {
+                            socket.state = Some(state);
+                            socket.target
+                        }
                         // Socket must have been closed before we could send event.
                         None => continue,
                     };
@@ -169,8 +271,11 @@ impl<'gc> Sockets<'gc> {
                     Avm2::dispatch_event(&mut activation.context, io_error_evt, target.into());
                 }
                 SocketAction::Data(handle, data) => {
-                    let target = match activation.context.sockets.sockets.get(handle) {
-                        Some(socket) => socket.target,
+                    let target = match activation.context.sockets.sockets.get_mut(handle) {
+                        Some(socket) => {
+                            socket.bytes_received += data.len() as u64;
+                            socket.target
+                        }
                         // Socket must have been closed before we could send event.
                         None => continue,
                     };
@@ -198,7 +303,8 @@ impl<'gc> Sockets<'gc> {
                     Avm2::dispatch_event(&mut activation.context, progress_evt, target.into());
                 }
                 SocketAction::Close(handle) => {
-                    let target = match activation.context.sockets.sockets.get(handle) {
+                    // The peer closed the connection, so the socket stops being `connected`.
+                    let target = match activation.context.sockets.sockets.remove(handle) {
                         Some(socket) => socket.target,
                         // Socket must have been closed before we could send event.
                         None => continue,
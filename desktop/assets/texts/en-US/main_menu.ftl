@@ -10,13 +10,21 @@
 file-menu = File
 file-menu-open-quick = Open...
 file-menu-open-advanced = Open Advanced...
+file-menu-open-url = Open URL...
 file-menu-close = Close
 file-menu-reload = Reload
+file-menu-save-screenshot = Save Screenshot...
 file-menu-exit = Exit
 
 controls-menu = Controls
 controls-menu-suspend = Suspend
 controls-menu-resume = Resume
+controls-menu-step-frame = Step Frame
+controls-menu-mute = Mute
+controls-menu-unmute = Unmute
+
+view-menu = View
+view-menu-fullscreen = Fullscreen
 
 help-menu = Help
 help-menu-join-discord = Join Discord
@@ -30,4 +38,6 @@ debug-menu-open-stage = View Stage Info
 debug-menu-open-movie = View Movie
 debug-menu-open-movie-list = Show Known Movies
 debug-menu-search-display-objects = Search Display Objects...
+debug-menu-open-socket-list = Show Sockets
+debug-menu-open-render-info = Show Render Info
 
@@ -2,6 +2,10 @@ open-dialog = Open File or URL
 
 open-dialog-path = File or URL
 
+open-url-dialog = Open URL
+open-url-dialog-url = URL
+open-url-dialog-invalid = Not a valid URL: { $error }
+
 open-dialog-add-parameter = Add
 open-dialog-remove-parameter = Remove
 open-dialog-remove-parameters = Remove all
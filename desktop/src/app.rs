@@ -1,14 +1,16 @@
 use crate::cli::Opt;
 use crate::custom_event::RuffleEvent;
-use crate::gui::{GuiController, MENU_HEIGHT};
+use crate::gui::{GuiController, MovieView, MENU_HEIGHT};
 use crate::player::{PlayerController, PlayerOptions};
 use crate::util::{
     get_screen_size, parse_url, pick_file, plot_stats_in_tracy, winit_key_to_char,
     winit_to_ruffle_key_code, winit_to_ruffle_text_control,
 };
 use anyhow::{Context, Error};
+use rfd::FileDialog;
 use ruffle_core::{PlayerEvent, StageDisplayState};
 use ruffle_render::backend::ViewportDimensions;
+use ruffle_render_wgpu::backend::WgpuRenderBackend;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::time::{Duration, Instant};
@@ -87,6 +89,8 @@ impl App {
         let mut minimized = false;
         let mut modifiers = ModifiersState::empty();
         let mut fullscreen_down = false;
+        // Dropping several files at once sends one event per file, within the same batch.
+        let mut opened_dropped_file = false;
 
         if self.opt.movie_url.is_none() {
             // No SWF provided on command line; show window with dummy movie immediately.
@@ -107,6 +111,10 @@ impl App {
                     return;
                 }
 
+                winit::event::Event::NewEvents(_) => {
+                    opened_dropped_file = false;
+                }
+
                 // Core loop
                 winit::event::Event::MainEventsCleared
                     if matches!(loaded, LoadingState::Loaded) =>
@@ -186,12 +194,17 @@ impl App {
                             check_redraw = true;
                         }
                         WindowEvent::DroppedFile(file) => {
-                            if let Ok(url) = parse_url(&file) {
+                            if opened_dropped_file {
+                                tracing::warn!(
+                                    "Ignoring dropped file {file:?}, only the first one is opened"
+                                );
+                            } else if let Ok(url) = parse_url(&file) {
                                 self.gui.borrow_mut().create_movie(
                                     &mut self.player,
                                     PlayerOptions::from(&self.opt),
                                     url,
                                 );
+                                opened_dropped_file = true;
                             }
                         }
                         WindowEvent::MouseInput { button, state, .. } => {
@@ -436,6 +449,51 @@ impl App {
                     self.player.destroy();
                 }
 
+                winit::event::Event::UserEvent(RuffleEvent::StepFrame) => {
+                    if let Some(mut player) = self.player.get() {
+                        player.step_frame();
+                    }
+                    check_redraw = true;
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::ToggleFullscreen) => {
+                    if let Some(mut player) = self.player.get() {
+                        // Go through the stage, so its display state stays in sync.
+                        player.update(|uc| {
+                            uc.stage.toggle_display_state(uc);
+                        });
+                    } else if self.window.fullscreen().is_some() {
+                        self.window.set_fullscreen(None);
+                    } else {
+                        self.window
+                            .set_fullscreen(Some(Fullscreen::Borderless(None)));
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SaveScreenshot) => {
+                    if let Some(mut player) = self.player.get() {
+                        let renderer = player
+                            .renderer_mut()
+                            .downcast_mut::<WgpuRenderBackend<MovieView>>()
+                            .expect("Renderer must be correct type");
+                        if let Some(png) = renderer.target().capture_png(renderer.descriptors()) {
+                            std::thread::spawn(move || {
+                                if let Some(path) = FileDialog::new()
+                                    .add_filter("PNG", &["png"])
+                                    .set_file_name("screenshot.png")
+                                    .save_file()
+                                {
+                                    if let Err(e) = std::fs::write(&path, png) {
+                                        tracing::error!(
+                                            "Couldn't save screenshot to {path:?}: {e}"
+                                        );
+                                    }
+                                }
+                            });
+                        }
+                    }
+                }
+
                 winit::event::Event::UserEvent(RuffleEvent::ExitRequested) => {
                     *control_flow = ControlFlow::Exit;
                     return;
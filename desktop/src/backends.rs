@@ -6,6 +6,6 @@ mod ui;
 
 pub use audio::CpalAudioBackend;
 pub use external_interface::DesktopExternalInterfaceProvider;
-pub use navigator::ExternalNavigatorBackend;
+pub use navigator::{ConnectionPoolOptions, ExternalNavigatorBackend};
 pub use storage::DiskStorageBackend;
 pub use ui::DesktopUiBackend;
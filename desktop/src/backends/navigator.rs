@@ -7,28 +7,62 @@ use futures::future::select;
 use futures::{AsyncReadExt, AsyncWriteExt};
 use futures_lite::FutureExt;
 use isahc::http::{HeaderName, HeaderValue};
-use isahc::{
-    config::RedirectPolicy, prelude::*, AsyncReadResponseExt, HttpClient, Request as IsahcRequest,
-};
-use rfd::{AsyncMessageDialog, MessageButtons, MessageDialog, MessageLevel};
+use isahc::{config::RedirectPolicy, prelude::*, HttpClient, Request as IsahcRequest};
+use rfd::{AsyncMessageDialog, FileDialog, MessageButtons, MessageDialog, MessageLevel};
 use ruffle_core::backend::navigator::{
-    async_return, create_fetch_error, create_specific_fetch_error, ErrorResponse, NavigationMethod,
-    NavigatorBackend, OpenURLMode, OwnedFuture, Request, SocketMode, SuccessResponse,
+    async_return, create_fetch_error, create_specific_fetch_error, ErrorResponse, FetchProgress,
+    NavigationMethod, NavigatorBackend, OpenURLMode, OwnedFuture, Request, SocketMode,
+    SuccessResponse,
 };
+use ruffle_core::config::NetworkingAccessMode;
 use ruffle_core::indexmap::IndexMap;
 use ruffle_core::loader::Error;
 use ruffle_core::socket::{ConnectionState, SocketAction, SocketHandle};
-use std::collections::HashSet;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::io;
 use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::str::FromStr;
 use std::sync::mpsc::{Receiver, Sender, TryRecvError};
-use std::time::Duration;
-use tracing::warn;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn, Instrument};
 use url::{ParseError, Url};
 use winit::event_loop::EventLoopProxy;
 
+/// Settings for reusing connections between requests made by the HTTP client.
+///
+/// Movies that poll an endpoint benefit from keeping connections open, as each
+/// new connection needs its own TCP (and possibly TLS) handshake.
+#[derive(Clone, Copy, Debug)]
+pub struct ConnectionPoolOptions {
+    /// The maximum number of idle connections kept open for reuse.
+    ///
+    /// Defaults to 16.
+    pub max_idle_connections: usize,
+
+    /// How long a connection may be kept open for reuse.
+    ///
+    /// Defaults to 60 seconds.
+    pub idle_timeout: Duration,
+
+    /// The interval of TCP keep-alive probes sent on open connections.
+    ///
+    /// Defaults to 30 seconds.
+    pub tcp_keepalive: Duration,
+}
+
+impl Default for ConnectionPoolOptions {
+    fn default() -> Self {
+        Self {
+            max_idle_connections: 16,
+            idle_timeout: Duration::from_secs(60),
+            tcp_keepalive: Duration::from_secs(30),
+        }
+    }
+}
+
 /// Implementation of `NavigatorBackend` for non-web environments that can call
 /// out to a web browser.
 pub struct ExternalNavigatorBackend {
@@ -48,8 +82,20 @@ pub struct ExternalNavigatorBackend {
 
     socket_mode: SocketMode,
 
+    /// The answers the user gave to `SocketMode::Ask` prompts, keyed by `host:port`.
+    /// These are kept for as long as this movie is open, so reconnects don't prompt again.
+    socket_decisions: Rc<RefCell<HashMap<String, bool>>>,
+
+    /// The SOCKS5 proxy (host and port) to tunnel socket connections through, if any.
+    socks_proxy: Option<(String, u16)>,
+
+    allow_networking: NetworkingAccessMode,
+
     upgrade_to_https: bool,
 
+    /// The maximum number of redirects to follow for a single request.
+    max_redirects: u32,
+
     open_url_mode: OpenURLMode,
 }
 
@@ -61,15 +107,26 @@ impl ExternalNavigatorBackend {
         channel: Sender<OwnedFuture<(), Error>>,
         event_loop: EventLoopProxy<RuffleEvent>,
         proxy: Option<Url>,
+        max_redirects: u32,
+        connection_pool: ConnectionPoolOptions,
         upgrade_to_https: bool,
         open_url_mode: OpenURLMode,
         socket_allowed: HashSet<String>,
         socket_mode: SocketMode,
+        allow_networking: NetworkingAccessMode,
     ) -> Self {
+        // SOCKS5 proxies can tunnel arbitrary TCP connections, so sockets use them too.
+        let socks_proxy = proxy
+            .as_ref()
+            .filter(|url| matches!(url.scheme(), "socks5" | "socks5h"))
+            .and_then(|url| Some((url.host_str()?.to_owned(), url.port().unwrap_or(1080))));
         let proxy = proxy.and_then(|url| url.as_str().parse().ok());
         let builder = HttpClient::builder()
             .proxy(proxy)
-            .redirect_policy(RedirectPolicy::Follow);
+            .redirect_policy(RedirectPolicy::Limit(max_redirects))
+            .connection_cache_size(connection_pool.max_idle_connections)
+            .connection_cache_ttl(connection_pool.idle_timeout)
+            .tcp_keepalive(connection_pool.tcp_keepalive);
 
         let client = builder.build().ok().map(Rc::new);
 
@@ -85,13 +142,113 @@ impl ExternalNavigatorBackend {
             client,
             base_url,
             upgrade_to_https,
+            max_redirects,
             open_url_mode,
             socket_allowed,
             socket_mode,
+            socket_decisions: Default::default(),
+            socks_proxy,
+            allow_networking,
         }
     }
 }
 
+/// Returns the local directory that the movie at `base_url` is allowed to read from.
+///
+/// The base URL usually points at the movie file itself, in which case its containing
+/// directory is used.
+fn base_directory(base_url: &Url) -> Option<PathBuf> {
+    base_url.join(".").ok()?.to_file_path().ok()
+}
+
+/// Checks whether `path` resolves to a location outside of `directory`.
+///
+/// Paths that can't be canonicalized (e.g. because they don't exist) are not
+/// considered to be outside, reading them will fail on its own.
+fn is_outside_directory(path: &Path, directory: &Path) -> bool {
+    match (path.canonicalize(), directory.canonicalize()) {
+        (Ok(path), Ok(directory)) => !path.starts_with(directory),
+        _ => false,
+    }
+}
+
+/// Asks the user to grant read access to the directory containing `path`.
+///
+/// Returns whether the user agreed to do so.
+fn request_sandbox_read_access(path: &Path) -> bool {
+    let attempt_sandbox_open = MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_description(&format!("The current movie is attempting to read files stored in {}.\n\nTo allow it to do so, click Yes, and then Open to grant read access to that directory.\n\nOtherwise, click No to deny access.", path.parent().unwrap_or(path).to_string_lossy()))
+        .set_buttons(MessageButtons::YesNo)
+        .show();
+
+    if attempt_sandbox_open {
+        FileDialog::new().set_directory(path).pick_folder();
+    }
+
+    attempt_sandbox_open
+}
+
+/// Opens a TCP connection to `host:port`, tunneled through the SOCKS5 proxy at `proxy`.
+///
+/// Only proxies that don't require authentication are supported (see RFC 1928).
+async fn connect_through_socks5(
+    proxy: (String, u16),
+    host: &str,
+    port: u16,
+) -> io::Result<TcpStream> {
+    let mut stream = TcpStream::connect(proxy).await?;
+
+    // Greeting: version 5, offering the "no authentication" method only.
+    stream.write_all(&[5, 1, 0]).await?;
+    let mut reply = [0; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply != [5, 0] {
+        return Err(io::Error::new(
+            ErrorKind::Other,
+            "SOCKS5 proxy requires an unsupported authentication method",
+        ));
+    }
+
+    // Connect request, letting the proxy resolve the host name.
+    let host_len = u8::try_from(host.len())
+        .map_err(|_| io::Error::new(ErrorKind::InvalidInput, "Host name is too long"))?;
+    let mut request = vec![5, 1, 0, 3, host_len];
+    request.extend_from_slice(host.as_bytes());
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[1] != 0 {
+        return Err(io::Error::new(
+            ErrorKind::ConnectionRefused,
+            format!("SOCKS5 proxy failed to connect (reply code {})", reply[1]),
+        ));
+    }
+
+    // Skip the address the proxy bound for us, followed by its port.
+    let address_len = match reply[3] {
+        1 => 4,
+        4 => 16,
+        3 => {
+            let mut len = [0; 1];
+            stream.read_exact(&mut len).await?;
+            len[0].into()
+        }
+        _ => {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "SOCKS5 proxy replied with an unknown address type",
+            ))
+        }
+    };
+    let mut bound_address = vec![0; address_len + 2];
+    stream.read_exact(&mut bound_address).await?;
+
+    Ok(stream)
+}
+
 impl NavigatorBackend for ExternalNavigatorBackend {
     fn navigate_to_url(
         &self,
@@ -178,6 +335,8 @@ impl NavigatorBackend for ExternalNavigatorBackend {
         };
 
         let client = self.client.clone();
+        let max_redirects = self.max_redirects;
+        let base_dir = base_directory(&self.base_url);
 
         match processed_url.scheme() {
             "file" => Box::pin(async move {
@@ -200,29 +359,39 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                     }
                 };
 
-                let body = match std::fs::read(&path).or_else(|e| {
-                    if cfg!(feature = "sandbox") {
-                        use rfd::FileDialog;
-
-                        if e.kind() == ErrorKind::PermissionDenied {
-                            let attempt_sandbox_open = MessageDialog::new()
-                                .set_level(MessageLevel::Warning)
-                                .set_description(&format!("The current movie is attempting to read files stored in {}.\n\nTo allow it to do so, click Yes, and then Open to grant read access to that directory.\n\nOtherwise, click No to deny access.", path.parent().unwrap_or(&path).to_string_lossy()))
-                                .set_buttons(MessageButtons::YesNo)
-                                .show();
-
-                            if attempt_sandbox_open {
-                                FileDialog::new().set_directory(&path).pick_folder();
-
-                                return std::fs::read(&path);
-                            }
+                // Reading files outside of the movie's directory needs the user's approval.
+                if cfg!(feature = "sandbox") {
+                    if let Some(base_dir) = &base_dir {
+                        if is_outside_directory(&path, base_dir)
+                            && !request_sandbox_read_access(&path)
+                        {
+                            return create_specific_fetch_error(
+                                "Access denied to file outside of the movie's directory",
+                                response_url.as_str(),
+                                "",
+                            );
                         }
                     }
+                }
+
+                let body = match std::fs::read(&path).or_else(|e| {
+                    if cfg!(feature = "sandbox")
+                        && e.kind() == ErrorKind::PermissionDenied
+                        && request_sandbox_read_access(&path)
+                    {
+                        return std::fs::read(&path);
+                    }
 
                     Err(e)
                 }) {
                     Ok(body) => body,
-                    Err(e) => return create_specific_fetch_error("Can't open file", response_url.as_str(), e)
+                    Err(e) => {
+                        return create_specific_fetch_error(
+                            "Can't open file",
+                            response_url.as_str(),
+                            e,
+                        )
+                    }
                 };
 
                 Ok(SuccessResponse {
@@ -263,9 +432,16 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                     error: Error::FetchError(e.to_string()),
                 })?;
 
-                let mut response = client.send_async(body).await.map_err(|e| ErrorResponse {
-                    url: processed_url.to_string(),
-                    error: Error::FetchError(e.to_string()),
+                let mut response = client.send_async(body).await.map_err(|e| {
+                    let message = if e.kind() == isahc::error::ErrorKind::TooManyRedirects {
+                        format!("Too many redirects (the limit is {max_redirects})")
+                    } else {
+                        e.to_string()
+                    };
+                    ErrorResponse {
+                        url: processed_url.to_string(),
+                        error: Error::FetchError(message),
+                    }
                 })?;
 
                 let url = if let Some(uri) = response.effective_uri() {
@@ -276,24 +452,48 @@ impl NavigatorBackend for ExternalNavigatorBackend {
 
                 let status = response.status().as_u16();
                 let redirected = response.effective_uri().is_some();
-                if !response.status().is_success() {
+                let is_success = response.status().is_success();
+
+                // Read the body in chunks, so that the download progress can be reported.
+                // Error responses don't report any progress, as the movie gets an `ioError` instead.
+                let progress = request.progress_sender().filter(|_| is_success);
+                let bytes_total = response.body().len();
+                let mut body = vec![];
+                let mut buffer = [0; 8192];
+                loop {
+                    let read = match response.body_mut().read(&mut buffer).await {
+                        Ok(0) => break,
+                        Ok(read) => read,
+                        Err(e) => {
+                            return Err(ErrorResponse {
+                                url,
+                                error: Error::FetchError(e.to_string()),
+                            })
+                        }
+                    };
+
+                    body.extend_from_slice(&buffer[..read]);
+                    if let Some(progress) = progress {
+                        // The loader may have been dropped already, that's fine.
+                        let _ = progress.send(FetchProgress {
+                            bytes_loaded: body.len() as u64,
+                            bytes_total,
+                        });
+                    }
+                }
+
+                // The body is still passed along for error statuses, as it may contain
+                // an error payload the movie wants to look at.
+                if !is_success {
                     let error = Error::HttpNotOk(
                         format!("HTTP status is not ok, got {}", response.status()),
                         status,
                         redirected,
+                        body,
                     );
                     return Err(ErrorResponse { url, error });
                 }
 
-                let mut body = vec![];
-                response
-                    .copy_to(&mut body)
-                    .await
-                    .map_err(|e| ErrorResponse {
-                        url: url.clone(),
-                        error: Error::FetchError(e.to_string()),
-                    })?;
-
                 Ok(SuccessResponse {
                     url,
                     body,
@@ -337,9 +537,21 @@ impl NavigatorBackend for ExternalNavigatorBackend {
         receiver: Receiver<Vec<u8>>,
         sender: Sender<SocketAction>,
     ) {
+        // If `allowNetworking` is set to `none`, sockets are not allowed to connect.
+        if self.allow_networking == NetworkingAccessMode::None {
+            warn!("SWF tried to open a socket, but opening a socket is not allowed");
+            sender
+                .send(SocketAction::Connect(handle, ConnectionState::Failed))
+                .expect("working channel send");
+            return;
+        }
+
         let addr = format!("{}:{}", host, port);
         let is_allowed = self.socket_allowed.contains(&addr);
         let socket_mode = self.socket_mode;
+        let socket_decisions = self.socket_decisions.clone();
+        let socks_proxy = self.socks_proxy.clone();
+        let span = tracing::debug_span!("socket", host = %host, port, ?handle);
 
         let future = Box::pin(async move {
             match (is_allowed, socket_mode) {
@@ -357,9 +569,17 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                     return Ok(());
                 }
                 (false, SocketMode::Ask) => {
-                    let attempt_sandbox_connect = AsyncMessageDialog::new().set_level(MessageLevel::Warning).set_description(&format!("The current movie is attempting to connect to {:?} (port {}).\n\nTo allow it to do so, click Yes to grant network access to that host.\n\nOtherwise, click No to deny access.", host, port)).set_buttons(MessageButtons::YesNo)
-                    .show()
-                    .await;
+                    let remembered = socket_decisions.borrow().get(&addr).copied();
+                    let attempt_sandbox_connect = match remembered {
+                        Some(decision) => decision,
+                        None => {
+                            let decision = AsyncMessageDialog::new().set_level(MessageLevel::Warning).set_description(&format!("The current movie is attempting to connect to {:?} (port {}).\n\nTo allow it to do so, click Yes to grant network access to that host.\n\nOtherwise, click No to deny access.\n\nYour choice will be remembered while this movie is open.", host, port)).set_buttons(MessageButtons::YesNo)
+                            .show()
+                            .await;
+                            socket_decisions.borrow_mut().insert(addr, decision);
+                            decision
+                        }
+                    };
 
                     if !attempt_sandbox_connect {
                         // fail the connection.
@@ -374,7 +594,16 @@ impl NavigatorBackend for ExternalNavigatorBackend {
 
             let host2 = host.clone();
 
-            let stream = match TcpStream::connect((host, port))
+            debug!(proxied = socks_proxy.is_some(), "Connecting");
+            let connect = async move {
+                match socks_proxy {
+                    Some(proxy) => connect_through_socks5(proxy, &host, port).await,
+                    None => TcpStream::connect((host, port)).await,
+                }
+            };
+
+            let connect_start = Instant::now();
+            let stream = match connect
                 .or(async {
                     Timer::after(timeout).await;
                     Result::<TcpStream, io::Error>::Err(io::Error::new(ErrorKind::TimedOut, ""))
@@ -389,6 +618,7 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                     return Ok(());
                 }
                 Ok(stream) => {
+                    debug!(latency = ?connect_start.elapsed(), "Connected");
                     sender
                         .send(SocketAction::Connect(handle, ConnectionState::Connected))
                         .expect("working channel send");
@@ -416,6 +646,7 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                     match read.read(&mut buffer).await {
                         Err(e) if e.kind() == ErrorKind::TimedOut => {} // try again later.
                         Err(_) | Ok(0) => {
+                            debug!("Connection closed by the peer");
                             sender
                                 .send(SocketAction::Close(handle))
                                 .expect("working channel send");
@@ -423,6 +654,7 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                             break;
                         }
                         Ok(read) => {
+                            debug!(bytes = read, "Received data");
                             let buffer = buffer.into_iter().take(read).collect::<Vec<_>>();
 
                             sender
@@ -444,8 +676,19 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                             }
                             Err(TryRecvError::Disconnected) => {
                                 //NOTE: Channel sender has been dropped.
-                                //      This means we have to close the connection.
-                                drop(write);
+                                //      This means we have to close the connection,
+                                //      but only after everything the movie sent has gone out.
+                                if !pending_write.is_empty() {
+                                    match write.write_all(&pending_write).await {
+                                        Ok(()) => debug!(bytes = pending_write.len(), "Sent data"),
+                                        Err(e) => debug!("Dropped unsent data on close: {}", e),
+                                    }
+                                }
+                                debug!("Connection closed by the movie");
+                                // Shut down our side of the stream, so the peer sees a clean close.
+                                if let Err(e) = write.close().await {
+                                    debug!("Failed to shut down the connection: {}", e);
+                                }
                                 return;
                             }
                             Err(_) => break,
@@ -455,7 +698,8 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                     if !pending_write.is_empty() {
                         match write.write(&pending_write).await {
                             Err(e) if e.kind() == ErrorKind::TimedOut => {} // try again later.
-                            Err(_) => {
+                            Err(e) => {
+                                debug!("Connection closed after a write error: {}", e);
                                 sender2
                                     .send(SocketAction::Close(handle))
                                     .expect("working channel send");
@@ -463,6 +707,7 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                                 return;
                             }
                             Ok(written) => {
+                                debug!(bytes = written, "Sent data");
                                 let _ = pending_write.drain(..written);
                             }
                         }
@@ -481,6 +726,35 @@ impl NavigatorBackend for ExternalNavigatorBackend {
             Ok(())
         });
 
-        self.spawn_future(future);
+        self.spawn_future(Box::pin(future.instrument(span)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_base_url_allows_sibling_files() {
+        let root = std::env::temp_dir().join(format!("ruffle_navigator_{}", std::process::id()));
+        let movie_dir = root.join("movie");
+        std::fs::create_dir_all(&movie_dir).unwrap();
+        let movie = movie_dir.join("movie.swf");
+        let sibling = movie_dir.join("data.txt");
+        let outside = root.join("outside.txt");
+        for file in [&movie, &sibling, &outside] {
+            std::fs::write(file, b"").unwrap();
+        }
+
+        let base_dir = base_directory(&Url::from_file_path(&movie).unwrap()).unwrap();
+        assert!(!is_outside_directory(&sibling, &base_dir));
+        assert!(!is_outside_directory(&movie, &base_dir));
+        assert!(is_outside_directory(&outside, &base_dir));
+
+        let dir_base = base_directory(&Url::from_directory_path(&movie_dir).unwrap()).unwrap();
+        assert!(!is_outside_directory(&sibling, &dir_base));
+        assert!(is_outside_directory(&outside, &dir_base));
+
+        std::fs::remove_dir_all(&root).unwrap();
     }
 }
@@ -2,7 +2,7 @@ use crate::RUFFLE_VERSION;
 use anyhow::Error;
 use clap::Parser;
 use ruffle_core::backend::navigator::{OpenURLMode, SocketMode};
-use ruffle_core::config::Letterbox;
+use ruffle_core::config::{Letterbox, NetworkingAccessMode};
 use ruffle_core::{LoadBehavior, StageAlign, StageScaleMode};
 use ruffle_render::quality::StageQuality;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
@@ -35,6 +35,11 @@ pub struct Opt {
     #[clap(long, short, default_value = "high")]
     pub power: PowerPreference,
 
+    /// Name of the graphics adapter to use, such as "NVIDIA" or "Radeon". Any adapter whose name
+    /// contains this text (ignoring case) may be picked. Overrides the power preference.
+    #[clap(long)]
+    pub graphics_adapter: Option<String>,
+
     /// Width of window in pixels.
     #[clap(long, display_order = 1)]
     pub width: Option<f64>,
@@ -82,10 +87,17 @@ pub struct Opt {
     trace_path: Option<std::path::PathBuf>,
 
     /// Proxy to use when loading movies via URL.
+    ///
+    /// SOCKS5 proxies (`socks5://` or `socks5h://`) are also used for socket connections.
     #[clap(long)]
     pub proxy: Option<Url>,
 
+    /// Maximum number of HTTP redirects to follow for a single request.
+    #[clap(long, default_value = "20")]
+    pub max_redirects: u32,
+
     /// Add an endpoint (`[host]:[port]`) to the socket whitelist.
+    /// With `--socket-mode deny`, only whitelisted endpoints may be connected to.
     #[clap(long = "socket-allow", number_of_values = 1, action = clap::ArgAction::Append)]
     pub socket_allow: Vec<String>,
 
@@ -93,6 +105,10 @@ pub struct Opt {
     #[clap(long = "socket-mode", default_value = "ask")]
     pub socket_mode: SocketMode,
 
+    /// Which networking APIs movies may use. With `none`, socket connections are refused.
+    #[clap(long, default_value = "all")]
+    pub allow_networking: NetworkingAccessMode,
+
     /// Replace all embedded HTTP URLs with HTTPS.
     #[clap(long, action)]
     pub upgrade_to_https: bool,
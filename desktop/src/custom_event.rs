@@ -19,6 +19,15 @@ pub enum RuffleEvent {
     /// The user requested to close the current SWF.
     CloseFile,
 
+    /// The user requested to save a screenshot of the current frame.
+    SaveScreenshot,
+
+    /// The user requested to advance the suspended player by one frame.
+    StepFrame,
+
+    /// The user requested to enter or leave fullscreen.
+    ToggleFullscreen,
+
     /// The user requested to exit Ruffle.
     ExitRequested,
 
@@ -1,6 +1,7 @@
 mod controller;
 mod movie;
 mod open_dialog;
+mod open_url_dialog;
 
 pub use controller::GuiController;
 pub use movie::MovieView;
@@ -9,6 +10,7 @@ use url::Url;
 
 use crate::custom_event::RuffleEvent;
 use crate::gui::open_dialog::OpenDialog;
+use crate::gui::open_url_dialog::OpenUrlDialog;
 use crate::player::PlayerOptions;
 use chrono::DateTime;
 use egui::*;
@@ -17,7 +19,8 @@ use fluent_templates::{static_loader, Loader};
 use rfd::FileDialog;
 use ruffle_core::backend::ui::US_ENGLISH;
 use ruffle_core::debug_ui::Message as DebugMessage;
-use ruffle_core::Player;
+use ruffle_core::{Player, StageScaleMode};
+use ruffle_render::quality::StageQuality;
 use std::collections::HashMap;
 use std::fs;
 use sys_locale::get_locale;
@@ -40,7 +43,6 @@ pub fn text<'a>(locale: &LanguageIdentifier, id: &'a str) -> Cow<'a, str> {
     })
 }
 
-#[allow(dead_code)]
 pub fn text_with_args<'a, T: AsRef<str>>(
     locale: &LanguageIdentifier,
     id: &'a str,
@@ -55,6 +57,26 @@ pub fn text_with_args<'a, T: AsRef<str>>(
         })
 }
 
+/// Stage qualities offered in the Controls menu, with their text ids.
+const QUALITY_OPTIONS: [(StageQuality, &str); 8] = [
+    (StageQuality::Low, "quality-low"),
+    (StageQuality::Medium, "quality-medium"),
+    (StageQuality::High, "quality-high"),
+    (StageQuality::Best, "quality-best"),
+    (StageQuality::High8x8, "quality-high8x8"),
+    (StageQuality::High8x8Linear, "quality-high8x8linear"),
+    (StageQuality::High16x16, "quality-high16x16"),
+    (StageQuality::High16x16Linear, "quality-high16x16linear"),
+];
+
+/// Stage scale modes offered in the Controls menu, with their text ids.
+const SCALE_MODE_OPTIONS: [(StageScaleMode, &str); 4] = [
+    (StageScaleMode::ShowAll, "scale-mode-showall"),
+    (StageScaleMode::NoBorder, "scale-mode-noborder"),
+    (StageScaleMode::ExactFit, "scale-mode-exactfit"),
+    (StageScaleMode::NoScale, "scale-mode-noscale"),
+];
+
 /// Size of the top menu bar in pixels.
 /// This is the offset at which the movie will be shown,
 /// and added to the window size if trying to match a movie.
@@ -67,10 +89,13 @@ pub struct RuffleGui {
     is_open_dialog_visible: bool,
     context_menu: Vec<ruffle_core::ContextMenuItem>,
     open_dialog: OpenDialog,
+    open_url_dialog: Option<OpenUrlDialog>,
     locale: LanguageIdentifier,
     default_player_options: PlayerOptions,
     currently_opened: Option<(Url, PlayerOptions)>,
     was_suspended_before_debug: bool,
+    /// The volume to restore when unmuting, or `None` if not muted.
+    volume_before_mute: Option<f32>,
 }
 
 impl RuffleGui {
@@ -91,6 +116,7 @@ impl RuffleGui {
             is_about_visible: false,
             is_open_dialog_visible: false,
             was_suspended_before_debug: false,
+            volume_before_mute: None,
 
             context_menu: vec![],
             open_dialog: OpenDialog::new(
@@ -99,6 +125,7 @@ impl RuffleGui {
                 event_loop.clone(),
                 locale.clone(),
             ),
+            open_url_dialog: None,
 
             event_loop,
             locale,
@@ -121,6 +148,7 @@ impl RuffleGui {
 
         self.about_window(egui_ctx);
         self.open_dialog(egui_ctx);
+        self.open_url_dialog(egui_ctx);
 
         if let Some(player) = player {
             let was_suspended = player.debug_ui().should_suspend_player();
@@ -166,6 +194,8 @@ impl RuffleGui {
     /// Notifies the GUI that a new player was created.
     fn on_player_created(&mut self, opt: PlayerOptions, movie_url: Url) {
         self.currently_opened = Some((movie_url.clone(), opt.clone()));
+        // New players always start with the configured volume.
+        self.volume_before_mute = None;
 
         // Update dialog state to reflect the newly-opened movie's options.
         self.is_open_dialog_visible = false;
@@ -196,6 +226,11 @@ impl RuffleGui {
             }) {
                 self.request_exit(ui);
             }
+            if player.is_some() && ui.ctx().input_mut(|input| {
+                input.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::R))
+            }) {
+                self.reload_movie(ui);
+            }
             if ui.ctx().input_mut(|input| {
                 input.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::P))
             }) {
@@ -203,6 +238,24 @@ impl RuffleGui {
                     player.set_is_playing(!player.is_playing());
                 }
             }
+            if ui.ctx().input_mut(|input| {
+                input.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::M))
+            }) {
+                if let Some(player) = &mut player {
+                    self.toggle_mute(player);
+                }
+            }
+            let paused = player.as_ref().map(|p| !p.is_playing()).unwrap_or_default();
+            if paused && ui.ctx().input_mut(|input| {
+                input.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowRight))
+            }) {
+                self.step_frame(ui);
+            }
+            if ui.ctx().input_mut(|input| {
+                input.consume_shortcut(&KeyboardShortcut::new(Modifiers::NONE, Key::F11))
+            }) {
+                self.toggle_fullscreen(ui);
+            }
 
             menu::bar(ui, |ui| {
                 menu::menu_button(ui, text(&self.locale, "file-menu"), |ui| {
@@ -225,7 +278,13 @@ impl RuffleGui {
                         self.open_file_advanced();
                     }
 
-                    if ui.add_enabled(player.is_some(), Button::new(text(&self.locale, "file-menu-reload"))).clicked() {
+                    if Button::new(text(&self.locale, "file-menu-open-url")).ui(ui).clicked() {
+                        ui.close_menu();
+                        self.open_url();
+                    }
+
+                    shortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::R);
+                    if ui.add_enabled(player.is_some(), Button::new(text(&self.locale, "file-menu-reload")).shortcut_text(ui.ctx().format_shortcut(&shortcut))).clicked() {
                         self.reload_movie(ui);
                     }
 
@@ -233,6 +292,10 @@ impl RuffleGui {
                         self.close_movie(ui);
                     }
 
+                    if ui.add_enabled(player.is_some(), Button::new(text(&self.locale, "file-menu-save-screenshot"))).clicked() {
+                        self.save_screenshot(ui);
+                    }
+
                     ui.separator();
 
                     shortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Q);
@@ -254,8 +317,53 @@ impl RuffleGui {
                                 player.set_is_playing(!player.is_playing());
                             }
                         }
+                        let step_shortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowRight);
+                        if ui.add_enabled(!playing, Button::new(text(&self.locale, "controls-menu-step-frame")).shortcut_text(ui.ctx().format_shortcut(&step_shortcut))).clicked() {
+                            self.step_frame(ui);
+                        }
+                        let muted = self.volume_before_mute.is_some();
+                        let mute_shortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::M);
+                        if Button::new(text(&self.locale, if muted { "controls-menu-unmute" } else { "controls-menu-mute" })).shortcut_text(ui.ctx().format_shortcut(&mute_shortcut)).ui(ui).clicked() {
+                            ui.close_menu();
+                            if let Some(player) = &mut player {
+                                self.toggle_mute(player);
+                            }
+                        }
+                        ui.separator();
+                        ui.menu_button(text(&self.locale, "quality"), |ui| {
+                            let current = player.as_mut().map(|p| p.quality());
+                            for (quality, id) in QUALITY_OPTIONS {
+                                if ui.radio(current == Some(quality), text(&self.locale, id)).clicked() {
+                                    ui.close_menu();
+                                    if let Some(player) = &mut player {
+                                        player.set_quality(quality);
+                                    }
+                                }
+                            }
+                        });
+                        ui.menu_button(text(&self.locale, "scale-mode"), |ui| {
+                            let current = player.as_mut().map(|p| p.scale_mode());
+                            for (scale_mode, id) in SCALE_MODE_OPTIONS {
+                                if ui.radio(current == Some(scale_mode), text(&self.locale, id)).clicked() {
+                                    ui.close_menu();
+                                    if let Some(player) = &mut player {
+                                        player.set_scale_mode(scale_mode);
+                                    }
+                                }
+                            }
+                        });
                     });
                 });
+                menu::menu_button(ui, text(&self.locale, "view-menu"), |ui| {
+                    let fullscreen_shortcut = KeyboardShortcut::new(Modifiers::NONE, Key::F11);
+                    if Button::new(text(&self.locale, "view-menu-fullscreen"))
+                        .shortcut_text(ui.ctx().format_shortcut(&fullscreen_shortcut))
+                        .ui(ui)
+                        .clicked()
+                    {
+                        self.toggle_fullscreen(ui);
+                    }
+                });
                 menu::menu_button(ui, text(&self.locale, "debug-menu"), |ui| {
                     ui.add_enabled_ui(player.is_some(), |ui| {
                         if Button::new(text(&self.locale, "debug-menu-open-stage")).ui(ui).clicked() {
@@ -282,6 +390,18 @@ impl RuffleGui {
                                 player.debug_ui().queue_message(DebugMessage::SearchForDisplayObject);
                             }
                         }
+                        if Button::new(text(&self.locale, "debug-menu-open-socket-list")).ui(ui).clicked() {
+                            ui.close_menu();
+                            if let Some(player) = &mut player {
+                                player.debug_ui().queue_message(DebugMessage::ShowSockets);
+                            }
+                        }
+                        if Button::new(text(&self.locale, "debug-menu-open-render-info")).ui(ui).clicked() {
+                            ui.close_menu();
+                            if let Some(player) = &mut player {
+                                player.debug_ui().queue_message(DebugMessage::ShowRenderInfo);
+                            }
+                        }
                     });
                 });
                 menu::menu_button(ui, text(&self.locale, "help-menu"), |ui| {
@@ -444,6 +564,14 @@ impl RuffleGui {
         self.is_open_dialog_visible = true;
     }
 
+    fn open_url(&mut self) {
+        self.open_url_dialog = Some(OpenUrlDialog::new(
+            self.default_player_options.clone(),
+            self.event_loop.clone(),
+            self.locale.clone(),
+        ));
+    }
+
     fn close_movie(&mut self, ui: &mut egui::Ui) {
         let _ = self.event_loop.send_event(RuffleEvent::CloseFile);
         self.currently_opened = None;
@@ -460,6 +588,30 @@ impl RuffleGui {
         ui.close_menu();
     }
 
+    fn step_frame(&mut self, ui: &mut egui::Ui) {
+        let _ = self.event_loop.send_event(RuffleEvent::StepFrame);
+        ui.close_menu();
+    }
+
+    fn toggle_fullscreen(&mut self, ui: &mut egui::Ui) {
+        let _ = self.event_loop.send_event(RuffleEvent::ToggleFullscreen);
+        ui.close_menu();
+    }
+
+    fn toggle_mute(&mut self, player: &mut Player) {
+        if let Some(volume) = self.volume_before_mute.take() {
+            player.set_volume(volume);
+        } else {
+            self.volume_before_mute = Some(player.volume());
+            player.set_volume(0.0);
+        }
+    }
+
+    fn save_screenshot(&mut self, ui: &mut egui::Ui) {
+        let _ = self.event_loop.send_event(RuffleEvent::SaveScreenshot);
+        ui.close_menu();
+    }
+
     fn open_dialog(&mut self, egui_ctx: &egui::Context) {
         if self.is_open_dialog_visible {
             let keep_open = self.open_dialog.show(egui_ctx);
@@ -467,6 +619,14 @@ impl RuffleGui {
         }
     }
 
+    fn open_url_dialog(&mut self, egui_ctx: &egui::Context) {
+        if let Some(dialog) = &mut self.open_url_dialog {
+            if !dialog.show(egui_ctx) {
+                self.open_url_dialog = None;
+            }
+        }
+    }
+
     fn request_exit(&mut self, ui: &mut egui::Ui) {
         let _ = self.event_loop.send_event(RuffleEvent::ExitRequested);
         ui.close_menu();
@@ -61,6 +61,7 @@ impl GuiController {
             &instance,
             Some(&surface),
             opt.power.into(),
+            opt.graphics_adapter.as_deref(),
             opt.trace_path(),
         ))
         .map_err(|e| anyhow!(e.to_string()))?;
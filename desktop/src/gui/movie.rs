@@ -1,6 +1,7 @@
 use crate::gui::MENU_HEIGHT;
 use ruffle_render_wgpu::descriptors::Descriptors;
 use ruffle_render_wgpu::target::{RenderTarget, RenderTargetFrame};
+use ruffle_render_wgpu::utils::{image_to_png, texture_to_image};
 use std::borrow::Cow;
 use std::sync::Arc;
 use wgpu::util::DeviceExt;
@@ -173,7 +174,9 @@ impl MovieView {
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format: wgpu::TextureFormat::Rgba8Unorm,
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC,
             view_formats: &[],
         });
         let view = texture.create_view(&Default::default());
@@ -208,6 +211,14 @@ impl MovieView {
         render_pass.set_vertex_buffer(0, renderer.vertices.slice(..));
         render_pass.draw(0..6, 0..1);
     }
+
+    /// Reads back the last rendered frame, encoded as a PNG file.
+    pub fn capture_png(&self, descriptors: &Descriptors) -> Option<Vec<u8>> {
+        let image = texture_to_image(descriptors, &self.texture);
+        image_to_png(&image)
+            .map_err(|e| tracing::error!("Couldn't encode screenshot as PNG: {e}"))
+            .ok()
+    }
 }
 
 impl RenderTarget for MovieView {
@@ -0,0 +1,100 @@
+use crate::custom_event::RuffleEvent;
+use crate::gui::{text, text_with_args};
+use crate::player::PlayerOptions;
+use egui::{Align2, Button, Key, TextEdit, Window};
+use fluent_templates::fluent_bundle::FluentValue;
+use std::collections::HashMap;
+use unic_langid::LanguageIdentifier;
+use url::Url;
+use winit::event_loop::EventLoopProxy;
+
+/// A small dialog for typing in the URL of a remote movie.
+pub struct OpenUrlDialog {
+    options: PlayerOptions,
+    event_loop: EventLoopProxy<RuffleEvent>,
+    locale: LanguageIdentifier,
+    value: String,
+    /// Why the last submitted value couldn't be parsed, if it couldn't.
+    error: Option<url::ParseError>,
+}
+
+impl OpenUrlDialog {
+    pub fn new(
+        options: PlayerOptions,
+        event_loop: EventLoopProxy<RuffleEvent>,
+        locale: LanguageIdentifier,
+    ) -> Self {
+        Self {
+            options,
+            event_loop,
+            locale,
+            value: "".to_string(),
+            error: None,
+        }
+    }
+
+    fn start(&mut self) -> bool {
+        match Url::parse(self.value.trim()) {
+            Ok(url) => {
+                self.error = None;
+                self.event_loop
+                    .send_event(RuffleEvent::OpenURL(url, Box::new(self.options.clone())))
+                    .is_ok()
+            }
+            Err(e) => {
+                self.error = Some(e);
+                false
+            }
+        }
+    }
+
+    pub fn show(&mut self, egui_ctx: &egui::Context) -> bool {
+        let mut keep_open = true;
+        let mut should_close = false;
+
+        Window::new(text(&self.locale, "open-url-dialog"))
+            .open(&mut keep_open)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .collapsible(false)
+            .resizable(false)
+            .show(egui_ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(text(&self.locale, "open-url-dialog-url"));
+                    let response = ui.add(
+                        TextEdit::singleline(&mut self.value)
+                            .hint_text("https://example.org/movie.swf")
+                            .desired_width(300.0),
+                    );
+                    if response.changed() {
+                        self.error = None;
+                    }
+                    if response.lost_focus() && ui.input(|input| input.key_pressed(Key::Enter)) {
+                        should_close = self.start();
+                    }
+                });
+
+                if let Some(error) = &self.error {
+                    let mut args = HashMap::new();
+                    args.insert("error", FluentValue::from(error.to_string()));
+                    ui.colored_label(
+                        ui.style().visuals.error_fg_color,
+                        text_with_args(&self.locale, "open-url-dialog-invalid", &args),
+                    );
+                }
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add_enabled(
+                            !self.value.trim().is_empty(),
+                            Button::new(text(&self.locale, "start")),
+                        )
+                        .clicked()
+                    {
+                        should_close = self.start();
+                    }
+                });
+            });
+
+        keep_open && !should_close
+    }
+}
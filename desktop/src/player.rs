@@ -1,6 +1,6 @@
 use crate::backends::{
-    CpalAudioBackend, DesktopExternalInterfaceProvider, DesktopUiBackend, DiskStorageBackend,
-    ExternalNavigatorBackend,
+    ConnectionPoolOptions, CpalAudioBackend, DesktopExternalInterfaceProvider, DesktopUiBackend,
+    DiskStorageBackend, ExternalNavigatorBackend,
 };
 use crate::cli::Opt;
 use crate::custom_event::RuffleEvent;
@@ -10,7 +10,7 @@ use crate::{CALLSTACK, RENDER_INFO, SWF_INFO};
 use anyhow::anyhow;
 use ruffle_core::backend::audio::AudioBackend;
 use ruffle_core::backend::navigator::{OpenURLMode, SocketMode};
-use ruffle_core::config::Letterbox;
+use ruffle_core::config::{Letterbox, NetworkingAccessMode};
 use ruffle_core::{LoadBehavior, Player, PlayerBuilder, PlayerEvent, StageAlign, StageScaleMode};
 use ruffle_render::backend::RenderBackend;
 use ruffle_render::quality::StageQuality;
@@ -38,8 +38,10 @@ pub struct PlayerOptions {
     pub volume: f32,
     pub force_scale: bool,
     pub proxy: Option<Url>,
+    pub max_redirects: u32,
     pub socket_allowed: HashSet<String>,
     pub socket_mode: SocketMode,
+    pub allow_networking: NetworkingAccessMode,
     pub upgrade_to_https: bool,
     pub fullscreen: bool,
     pub load_behavior: LoadBehavior,
@@ -64,6 +66,7 @@ impl From<&Opt> for PlayerOptions {
             volume: value.volume,
             force_scale: value.force_scale,
             proxy: value.proxy.clone(),
+            max_redirects: value.max_redirects,
             upgrade_to_https: value.upgrade_to_https,
             fullscreen: value.fullscreen,
             load_behavior: value.load_behavior,
@@ -75,6 +78,7 @@ impl From<&Opt> for PlayerOptions {
             dummy_external_interface: value.dummy_external_interface,
             socket_allowed: HashSet::from_iter(value.socket_allow.iter().cloned()),
             socket_mode: value.socket_mode,
+            allow_networking: value.allow_networking,
         }
     }
 }
@@ -113,10 +117,13 @@ impl ActivePlayer {
             channel,
             event_loop.clone(),
             opt.proxy.clone(),
+            opt.max_redirects,
+            ConnectionPoolOptions::default(),
             opt.upgrade_to_https,
             opt.open_url_mode,
             opt.socket_allowed.clone(),
             opt.socket_mode,
+            opt.allow_networking,
         );
 
         if cfg!(feature = "software_video") {
@@ -410,6 +410,7 @@ fn main() -> Result<()> {
         &instance,
         None,
         opt.power.into(),
+        None,
         trace_path(&opt),
     ))
     .map_err(|e| anyhow!(e.to_string()))?;
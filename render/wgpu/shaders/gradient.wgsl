@@ -60,7 +60,8 @@ fn find_t(uv: vec2<f32>) -> f32 {
         let uv = uv * 2.0 - 1.0;
         var d: vec2<f32> = vec2<f32>(gradient.focal_point, 0.0) - uv;
         let l = length(d);
-        d = d / l;
+        // Guard against the singularity at the focal point itself, where `d` has no direction.
+        d = d / max(l, 0.000001);
         return l / (sqrt(1.0 - gradient.focal_point * gradient.focal_point * d.y * d.y) + gradient.focal_point * d.x);
     }
 }
@@ -71,6 +71,7 @@ impl WgpuRenderBackend<SwapChainTarget> {
             Some(&surface),
             wgpu::PowerPreference::HighPerformance,
             None,
+            None,
         )
         .await?;
         let descriptors = Descriptors::new(instance, adapter, device, queue);
@@ -105,6 +106,7 @@ impl WgpuRenderBackend<SwapChainTarget> {
             &instance,
             Some(&surface),
             power_preference,
+            None,
             trace_path,
         ))?;
         let descriptors = Descriptors::new(instance, adapter, device, queue);
@@ -136,6 +138,7 @@ impl WgpuRenderBackend<crate::target::TextureTarget> {
             &instance,
             None,
             power_preference,
+            None,
             trace_path,
         ))?;
         let descriptors = Descriptors::new(instance, adapter, device, queue);
@@ -158,6 +161,14 @@ impl WgpuRenderBackend<crate::target::TextureTarget> {
             None
         }
     }
+
+    /// Captures the current frame and encodes it as a PNG image.
+    pub fn capture_frame_png(&self) -> Option<Vec<u8>> {
+        let image = self.capture_frame()?;
+        crate::utils::image_to_png(&image)
+            .map_err(|e| tracing::error!("Couldn't encode frame as PNG: {}", e))
+            .ok()
+    }
 }
 
 impl<T: RenderTarget> WgpuRenderBackend<T> {
@@ -328,11 +339,12 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
             }),
             Some(TextureBufferInfo {
                 buffer: MaybeOwnedBuffer::Borrowed(buffer, copy_dimensions),
-                ..
+                copy_area,
             }) => Box::new(QueueSyncHandle::AlreadyCopied {
                 index,
                 buffer,
                 copy_dimensions,
+                copy_area,
                 descriptors: self.descriptors.clone(),
             }),
             Some(TextureBufferInfo {
@@ -383,15 +395,9 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
             ),
             1,
         );
+        // Only the size changes here; the target keeps its format, so the surface can keep its pipelines.
         self.target.resize(&self.descriptors.device, width, height);
-
-        self.surface = Surface::new(
-            &self.descriptors,
-            self.surface.quality(),
-            width,
-            height,
-            self.target.format(),
-        );
+        self.surface.resize(width, height);
 
         self.viewport_scale_factor = dimensions.scale_factor;
         self.texture_pool = TexturePool::new();
@@ -434,6 +440,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
         result.push(format!("Surface quality: {}", self.surface.quality()));
         result.push(format!("Surface samples: {}", self.surface.sample_count()));
         result.push(format!("Surface size: {:?}", self.surface.size()));
+        result.push(format!("Target format: {:?}", self.target.format()));
 
         Cow::Owned(result.join("\n"))
     }
@@ -1043,8 +1050,18 @@ pub async fn request_adapter_and_device(
     instance: &wgpu::Instance,
     surface: Option<&wgpu::Surface>,
     power_preference: wgpu::PowerPreference,
+    adapter_name: Option<&str>,
     trace_path: Option<&Path>,
 ) -> Result<(wgpu::Adapter, wgpu::Device, wgpu::Queue), Error> {
+    #[cfg(not(target_family = "wasm"))]
+    if let Some(adapter_name) = adapter_name {
+        let adapter = find_adapter_by_name(backend, instance, surface, adapter_name)?;
+        let (device, queue) = request_device(&adapter, trace_path).await?;
+        return Ok((adapter, device, queue));
+    }
+    #[cfg(target_family = "wasm")]
+    let _ = adapter_name;
+
     let adapter = instance.request_adapter(&wgpu::RequestAdapterOptions {
         power_preference,
         compatible_surface: surface,
@@ -1061,10 +1078,54 @@ pub async fn request_adapter_and_device(
             }
         })?;
 
+    log_adapter(&adapter);
+
     let (device, queue) = request_device(&adapter, trace_path).await?;
     Ok((adapter, device, queue))
 }
 
+/// Picks the first adapter whose name contains `name` (ignoring case) and which can present to `surface`.
+#[cfg(not(target_family = "wasm"))]
+fn find_adapter_by_name(
+    backend: wgpu::Backends,
+    instance: &wgpu::Instance,
+    surface: Option<&wgpu::Surface>,
+    name: &str,
+) -> Result<wgpu::Adapter, Error> {
+    let needle = name.to_lowercase();
+    let mut available = Vec::new();
+    for adapter in instance.enumerate_adapters(backend) {
+        let info = adapter.get_info();
+        if surface.map_or(true, |surface| adapter.is_surface_supported(surface)) {
+            if info.name.to_lowercase().contains(&needle) {
+                log_adapter(&adapter);
+                return Ok(adapter);
+            }
+            available.push(format!("{} ({:?})", info.name, info.backend));
+        }
+    }
+
+    Err(if available.is_empty() {
+        format!("No graphics adapter matching \"{name}\" was found, and no other adapters are available")
+    } else {
+        format!(
+            "No graphics adapter matching \"{name}\" was found. Available adapters: {}",
+            available.join(", ")
+        )
+    }
+    .into())
+}
+
+fn log_adapter(adapter: &wgpu::Adapter) {
+    let info = adapter.get_info();
+    tracing::info!(
+        "Using graphics adapter {} ({:?}, {:?})",
+        info.name,
+        info.backend,
+        info.device_type
+    );
+}
+
 // We try to request the highest limits we can get away with
 async fn request_device(
     adapter: &wgpu::Adapter,
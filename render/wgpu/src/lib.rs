@@ -95,10 +95,17 @@ pub struct ColorAdjustments {
     add_color: [f32; 4],
 }
 
-pub const DEFAULT_COLOR_ADJUSTMENTS: ColorAdjustments = ColorAdjustments {
-    mult_color: [1.0, 1.0, 1.0, 1.0],
-    add_color: [0.0, 0.0, 0.0, 0.0],
-};
+pub const DEFAULT_COLOR_ADJUSTMENTS: ColorAdjustments = ColorAdjustments::with_alpha(1.0);
+
+impl ColorAdjustments {
+    /// Color adjustments that only scale alpha, leaving the color channels untouched.
+    pub const fn with_alpha(alpha: f32) -> Self {
+        Self {
+            mult_color: [1.0, 1.0, 1.0, alpha],
+            add_color: [0.0, 0.0, 0.0, 0.0],
+        }
+    }
+}
 
 impl From<&swf::ColorTransform> for ColorAdjustments {
     fn from(transform: &swf::ColorTransform) -> Self {
@@ -156,7 +163,7 @@ struct GradientUniforms {
 impl From<TessGradient> for GradientUniforms {
     fn from(gradient: TessGradient) -> Self {
         Self {
-            focal_point: gradient.focal_point.to_f32().clamp(-0.98, 0.98),
+            focal_point: gradient.focal_point.to_f32().clamp(-0.999, 0.999),
             interpolation: (gradient.interpolation == swf::GradientInterpolation::LinearRgb) as i32,
             shape: match gradient.gradient_type {
                 GradientType::Linear => 1,
@@ -178,6 +185,7 @@ pub enum QueueSyncHandle {
         index: wgpu::SubmissionIndex,
         buffer: PoolEntry<wgpu::Buffer, BufferDimensions>,
         copy_dimensions: BufferDimensions,
+        copy_area: PixelRegion,
         descriptors: Arc<Descriptors>,
     },
     NotCopied {
@@ -205,13 +213,20 @@ impl QueueSyncHandle {
                 index,
                 buffer,
                 copy_dimensions,
+                copy_area,
                 descriptors,
             } => capture_image(
                 &descriptors.device,
                 &buffer,
                 &copy_dimensions,
                 Some(index),
-                with_rgba,
+                |rgba, buffer_width| {
+                    // The buffer is sized for the whole texture, but only the rows
+                    // covering `copy_area` were written to (starting at offset 0).
+                    let row_bytes = copy_dimensions.padded_bytes_per_row as usize;
+                    let len = copy_area.height() as usize * row_bytes;
+                    with_rgba(&rgba[..len.min(rgba.len())], buffer_width)
+                },
             ),
             QueueSyncHandle::NotCopied {
                 handle,
@@ -322,6 +322,15 @@ impl Surface {
         target
     }
 
+    /// Changes the size of this surface, keeping its pipelines and sample count.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.size = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+    }
+
     pub fn quality(&self) -> StageQuality {
         self.quality
     }
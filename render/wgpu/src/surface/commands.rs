@@ -29,6 +29,9 @@ pub struct CommandRenderer<'pass, 'frame: 'pass, 'global: 'frame> {
     color_buffers: &'frame mut UniformBuffer<'global, ColorAdjustments>,
     uniform_encoder: &'frame mut wgpu::CommandEncoder,
     needs_depth: bool,
+    /// The color adjustments currently bound to group 2, if any.
+    /// Lets consecutive draws with the same color transform skip a uniform upload.
+    last_color_adjustments: Option<ColorAdjustments>,
 }
 
 impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'global> {
@@ -54,6 +57,7 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
             color_buffers,
             uniform_encoder,
             needs_depth,
+            last_color_adjustments: None,
         }
     }
 
@@ -214,6 +218,11 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
                 &Transforms { world_matrix },
             );
 
+            let colors = ColorAdjustments::from(color_adjustments);
+            if self.last_color_adjustments == Some(colors) {
+                return;
+            }
+
             if color_adjustments == &ColorTransform::IDENTITY {
                 self.render_pass.set_bind_group(
                     2,
@@ -227,9 +236,10 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
                     self.uniform_encoder,
                     &mut self.render_pass,
                     2,
-                    &color_adjustments.into(),
+                    &colors,
                 );
             }
+            self.last_color_adjustments = Some(colors);
         }
     }
 
@@ -184,6 +184,57 @@ pub fn buffer_to_image(
     })
 }
 
+/// Copies the contents of a texture back to the CPU.
+///
+/// The texture must be `Rgba8Unorm` and have been created with `COPY_SRC` usage.
+#[cfg(not(target_family = "wasm"))]
+pub fn texture_to_image(descriptors: &Descriptors, texture: &wgpu::Texture) -> image::RgbaImage {
+    let size = texture.size();
+    let dimensions = BufferDimensions::new(size.width as usize, size.height as usize);
+    let buffer = descriptors.device.create_buffer(&wgpu::BufferDescriptor {
+        label: create_debug_label!("Texture readback buffer").as_deref(),
+        size: dimensions.size(),
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    let label = create_debug_label!("Texture readback encoder");
+    let mut encoder = descriptors
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: label.as_deref(),
+        });
+    encoder.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(dimensions.padded_bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        size,
+    );
+    let index = descriptors.queue.submit(Some(encoder.finish()));
+    buffer_to_image(&descriptors.device, &buffer, &dimensions, Some(index), size)
+}
+
+/// Encodes an image as a PNG file.
+#[cfg(not(target_family = "wasm"))]
+pub fn image_to_png(image: &image::RgbaImage) -> Result<Vec<u8>, image::ImageError> {
+    let mut bytes = Vec::new();
+    image.write_to(
+        &mut std::io::Cursor::new(&mut bytes),
+        image::ImageOutputFormat::Png,
+    )?;
+    Ok(bytes)
+}
+
 pub fn supported_sample_count(
     adapter: &wgpu::Adapter,
     quality: StageQuality,
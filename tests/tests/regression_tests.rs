@@ -4,6 +4,7 @@
 
 use crate::external_interface::tests::{external_interface_avm1, external_interface_avm2};
 use crate::shared_object::{shared_object_avm1, shared_object_avm2, shared_object_self_ref_avm1};
+use crate::socket::{socket_stats_avm2, socket_timeout_avm2};
 use anyhow::Context;
 use anyhow::Result;
 use libtest_mimic::{Arguments, Trial};
@@ -12,6 +13,7 @@ use util::test::Test;
 
 mod external_interface;
 mod shared_object;
+mod socket;
 mod util;
 
 fn set_logger() {
@@ -98,6 +100,8 @@ fn main() {
         "external_interface_avm2",
         external_interface_avm2,
     ));
+    tests.push(Trial::test("socket_stats_avm2", socket_stats_avm2));
+    tests.push(Trial::test("socket_timeout_avm2", socket_timeout_avm2));
 
     tests.sort_unstable_by(|a, b| a.name().cmp(b.name()));
 
@@ -0,0 +1,68 @@
+use crate::set_logger;
+use crate::util::options::TestOptions;
+use crate::util::test::Test;
+use ruffle_core::socket::SocketStats;
+use std::path::Path;
+
+pub fn socket_stats_avm2() -> Result<(), libtest_mimic::Failed> {
+    set_logger();
+    // The test navigator fails every connection, so every attempt should be counted as a failure,
+    // including the one whose socket is closed before the result arrives.
+    Test::from_options(
+        TestOptions {
+            num_ticks: Some(2),
+            ..Default::default()
+        },
+        Path::new("tests/swfs/avm2/socket_stats/"),
+        "socket_stats_avm2".to_string(),
+    )?
+    .run(
+        |_| Ok(()),
+        |player| {
+            let stats = player.lock().unwrap().socket_stats();
+            std::assert_eq!(
+                stats,
+                SocketStats {
+                    attempts: 4,
+                    successes: 0,
+                    failures: 4,
+                    timeouts: 0,
+                }
+            );
+            Ok(())
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn socket_timeout_avm2() -> Result<(), libtest_mimic::Failed> {
+    set_logger();
+    Test::from_options(
+        TestOptions {
+            num_ticks: Some(1),
+            socket_timeout: true,
+            ..Default::default()
+        },
+        Path::new("tests/swfs/avm2/socket_timeout/"),
+        "socket_timeout_avm2".to_string(),
+    )?
+    .run(
+        |_| Ok(()),
+        |player| {
+            let player = player.lock().unwrap();
+            std::assert_eq!(
+                player.socket_stats(),
+                SocketStats {
+                    attempts: 1,
+                    successes: 0,
+                    failures: 0,
+                    timeouts: 1,
+                }
+            );
+            Ok(())
+        },
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+import flash.display.BitmapData;
+import flash.display.Shape;
+import flash.geom.Rectangle;
+
+// Draw into the bottom-right quadrant repeatedly, reading back after each draw.
+// After a few reads the renderer starts copying into a cached buffer, which must
+// still only hand back the clipped area.
+var bmd:BitmapData = new BitmapData(20, 20, false, 0x000000);
+var colors:Array = [0xFF0000, 0x00FF00, 0x0000FF, 0xFFFF00, 0x00FFFF, 0xFF00FF, 0xFFFFFF, 0x808080];
+
+function hex(value:uint):String {
+  return "0x" + value.toString(16).toUpperCase();
+}
+
+for (var i:int = 0; i < colors.length; i++) {
+  var shape:Shape = new Shape();
+  shape.graphics.beginFill(colors[i]);
+  shape.graphics.drawRect(0, 0, 20, 20);
+  shape.graphics.endFill();
+  bmd.draw(shape, null, null, null, new Rectangle(10, 10, 10, 10));
+  trace("// draw " + i);
+  trace("(0, 0): " + hex(bmd.getPixel(0, 0)));
+  trace("(9, 9): " + hex(bmd.getPixel(9, 9)));
+  trace("(10, 10): " + hex(bmd.getPixel(10, 10)));
+  trace("(19, 19): " + hex(bmd.getPixel(19, 19)));
+  trace("(19, 0): " + hex(bmd.getPixel(19, 0)));
+  trace("(0, 19): " + hex(bmd.getPixel(0, 19)));
+}
@@ -0,0 +1,28 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+import flash.errors.IOError;
+import flash.events.Event;
+import flash.events.IOErrorEvent;
+import flash.net.Socket;
+
+var socket:Socket = new Socket();
+trace("connected before connect(): " + socket.connected);
+
+socket.addEventListener(IOErrorEvent.IO_ERROR, function(e:IOErrorEvent):void {
+  trace("ioError, connected: " + socket.connected);
+});
+socket.addEventListener(Event.CLOSE, function(e:Event):void {
+  trace("close");
+});
+socket.connect("localhost", 8000);
+trace("connected while connecting: " + socket.connected);
+
+try {
+  socket.writeByte(1);
+  trace("writeByte() while connecting: no error");
+} catch (e:IOError) {
+  trace("writeByte() while connecting: " + e);
+}
@@ -0,0 +1,21 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+import flash.errors.IOError;
+import flash.events.IOErrorEvent;
+import flash.net.Socket;
+
+var socket:Socket = new Socket();
+socket.addEventListener(IOErrorEvent.IO_ERROR, function(e:IOErrorEvent):void {
+  trace("ioError");
+  try {
+    trace("flush() after failed connection");
+    socket.flush();
+    trace("no error");
+  } catch (err:IOError) {
+    trace(err);
+  }
+});
+socket.connect("localhost", 8000);
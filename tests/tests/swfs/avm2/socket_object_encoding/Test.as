@@ -0,0 +1,35 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+import flash.errors.IOError;
+import flash.net.Socket;
+
+var socket:Socket = new Socket();
+trace("default objectEncoding: " + socket.objectEncoding);
+
+socket.objectEncoding = 0;
+trace("after setting 0: " + socket.objectEncoding);
+
+try {
+  socket.objectEncoding = 1;
+} catch (e:ArgumentError) {
+  trace(e);
+}
+trace("after setting 1: " + socket.objectEncoding);
+
+socket.objectEncoding = 3;
+trace("after setting 3: " + socket.objectEncoding);
+
+try {
+  socket.writeObject({ a: 1 });
+} catch (e:IOError) {
+  trace(e);
+}
+
+try {
+  socket.readObject();
+} catch (e:IOError) {
+  trace(e);
+}
@@ -0,0 +1,36 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+import flash.errors.IOError;
+import flash.net.Socket;
+
+var socket:Socket = new Socket();
+socket.connect("localhost", 8000);
+try {
+  trace("writeUTFBytes() while connecting");
+  socket.writeUTFBytes("written before connecting");
+  trace("no error");
+} catch (e:IOError) {
+  trace(e);
+}
+
+// Closing a socket that is still connecting cancels the attempt.
+socket.close();
+
+try {
+  trace("flush() after close()");
+  socket.flush();
+  trace("no error");
+} catch (e:IOError) {
+  trace(e);
+}
+
+try {
+  trace("writeByte() after close()");
+  socket.writeByte(1);
+  trace("no error");
+} catch (e:IOError) {
+  trace(e);
+}
@@ -0,0 +1,26 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+import flash.events.Event;
+import flash.events.IOErrorEvent;
+import flash.net.Socket;
+
+var sockets:Array = [];
+
+for (var i:int = 0; i < 4; i++) {
+  var socket:Socket = new Socket();
+  socket.addEventListener(Event.CONNECT, function(e:Event):void {
+    trace("connect");
+  });
+  socket.addEventListener(IOErrorEvent.IO_ERROR, function(e:IOErrorEvent):void {
+    trace("ioError: " + e.text);
+  });
+  socket.connect("localhost", 8000 + i);
+  sockets.push(socket);
+}
+
+// The result of this connection attempt is never dispatched, but it is still counted.
+sockets[3].close();
+trace("started 4 connections");
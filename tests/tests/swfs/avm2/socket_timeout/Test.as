@@ -0,0 +1,26 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+import flash.errors.IOError;
+import flash.events.Event;
+import flash.events.IOErrorEvent;
+import flash.net.Socket;
+
+var socket:Socket = new Socket();
+socket.timeout = 250;
+
+socket.addEventListener(Event.CONNECT, function(e:Event):void {
+  trace("connect");
+});
+socket.addEventListener(IOErrorEvent.IO_ERROR, function(e:IOErrorEvent):void {
+  trace("ioError: " + e.text + " (errorID " + e.errorID + ")");
+  trace("connected: " + socket.connected);
+  try {
+    socket.close();
+  } catch (err:IOError) {
+    trace("close(): " + err);
+  }
+});
+socket.connect("localhost", 8000);
@@ -0,0 +1,45 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+function dumpDeclarations(label:String, xml:XML):void {
+    var decls:Array = xml.namespaceDeclarations();
+    trace(label + ": " + decls.length);
+    for each (var ns in decls) {
+        trace("  prefix: '" + ns.prefix + "' uri: " + ns.uri);
+    }
+}
+
+var xml:XML = <root xmlns:a="http://example.com/a"><child/></root>;
+dumpDeclarations("initial", xml);
+
+var result:XML = xml.addNamespace(new Namespace("b", "http://example.com/b"));
+trace("addNamespace returns receiver: " + (result === xml));
+dumpDeclarations("after adding b", xml);
+
+xml.addNamespace(new Namespace("a", "http://example.com/other"));
+dumpDeclarations("after replacing a", xml);
+trace(xml.toXMLString());
+
+xml.addNamespace(new Namespace("http://example.com/no-prefix"));
+dumpDeclarations("after adding namespace without prefix", xml);
+
+var used:XML = <root xmlns:u="http://example.com/used"><u:child/></root>;
+used.removeNamespace(new Namespace("u", "http://example.com/used"));
+dumpDeclarations("removing referenced namespace", used);
+
+result = xml.removeNamespace(new Namespace("b", "http://example.com/b"));
+trace("removeNamespace returns receiver: " + (result === xml));
+dumpDeclarations("after removing b", xml);
+
+xml.removeNamespace("http://example.com/other");
+dumpDeclarations("after removing by uri", xml);
+trace(xml.toXMLString());
+
+var text:XML = new XML("text");
+result = text.addNamespace(new Namespace("t", "http://example.com/t"));
+trace("text addNamespace returns receiver: " + (result === text));
+dumpDeclarations("text", text);
@@ -0,0 +1,21 @@
+package {
+	import flash.display.Sprite;
+	public class Test extends Sprite { }
+}
+
+var a:XML = <a><x/></a>;
+var b:XML = <b/>;
+
+// Appending a node elsewhere doesn't remove it from its previous parent.
+b.appendChild(a.x[0]);
+trace("a.children().length(): " + a.children().length());
+trace("b.children().length(): " + b.children().length());
+trace("a.x[0].parent().name(): " + a.x[0].parent().name());
+
+// Further changes to the previous parent must still work.
+a.appendChild(<y/>);
+a.prependChild(<w/>);
+trace("a.children().length(): " + a.children().length());
+for each (var child:XML in a.children()) {
+	trace("a child: " + child.name());
+}
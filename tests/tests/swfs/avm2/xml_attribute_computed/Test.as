@@ -0,0 +1,22 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+var xml:XML = <item id="42" name="first" value1="a" value2="b"/>;
+
+var names:Array = ["id", "name", "missing"];
+for each (var attrName:String in names) {
+    trace("xml.@[" + attrName + "] = " + xml.@[attrName]);
+}
+
+for (var i:int = 1; i <= 2; i++) {
+    trace("xml.@[\"value\" + " + i + "] = " + xml.@["value" + i]);
+}
+
+trace("xml.@[QName] = " + xml.@[new QName("name")]);
+trace("length of xml.@[\"missing\"] = " + xml.@["missing"].length());
+
+var list:XMLList = new XMLList("<a v=\"1\"/><a v=\"2\"/>");
+var listAttr:String = "v";
+trace("list.@[v] = " + list.@[listAttr]);
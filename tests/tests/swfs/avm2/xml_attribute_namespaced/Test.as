@@ -0,0 +1,23 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = <root xmlns:p="http://p" existing="plain" p:existing="old"/>;
+var ns = new Namespace("http://p");
+
+trace("get @ns::existing: " + xml.@ns::existing);
+
+xml.@ns::existing = "new";
+trace("set existing: " + xml.toXMLString());
+trace("get @ns::existing after set: " + xml.@ns::existing);
+
+xml.@ns::added = "value";
+trace("set added: " + xml.toXMLString());
+trace("get @ns::added: " + xml.@ns::added);
+
+delete xml.@ns::added;
+trace("delete added: " + xml.toXMLString());
+trace("get @ns::added after delete: " + xml.@ns::added.length());
@@ -0,0 +1,23 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = <root xmlns:ns="http://ns" xmlns:other="http://other"><ns:foo/><foo/><other:foo/><bar ns:attr="1" attr="2" other:attr="3"/></root>;
+var ns = new Namespace("http://ns");
+
+trace("before: " + xml.toXMLString());
+
+delete xml.ns::foo;
+trace("delete ns::foo: " + xml.toXMLString());
+
+delete xml.bar[0].@ns::attr;
+trace("delete @ns::attr: " + xml.toXMLString());
+
+delete xml.*::foo;
+trace("delete *::foo: " + xml.toXMLString());
+
+delete xml.bar[0].@*::attr;
+trace("delete @*::attr: " + xml.toXMLString());
@@ -0,0 +1,18 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = new XML('<a named="&lt;&gt;&amp;&quot;&apos;" numeric="&#65;&#x42;&#x63;" unknown="&foo;">&#67;&#x44; &lt;&gt;&amp;&quot;&apos; &unknown; &amp;amp;</a>');
+
+trace("named attribute: " + xml.@named);
+trace("numeric attribute: " + xml.@numeric);
+trace("unknown attribute: " + xml.@unknown);
+trace("text: " + xml.toString());
+trace("toXMLString: " + xml.toXMLString());
+
+var roundtrip:XML = new XML(xml.toXMLString());
+trace("roundtrip text: " + roundtrip.toString());
+trace("roundtrip named attribute: " + roundtrip.@named);
@@ -0,0 +1,30 @@
+package {
+	import flash.display.Sprite;
+	public class Test extends Sprite { }
+}
+
+import flash.utils.ByteArray;
+
+XML.prettyPrinting = false;
+
+var bytes:ByteArray = new ByteArray();
+bytes.writeUTFBytes("<root><item name=\"caf\u00e9\">first</item><item>second</item></root>");
+var xml:XML = new XML(bytes);
+trace("xml.item.length(): " + xml.item.length());
+trace("xml.item[0].@name: " + xml.item[0].@name);
+trace("xml.toXMLString(): " + xml.toXMLString());
+
+var withBom:ByteArray = new ByteArray();
+withBom.writeByte(0xEF);
+withBom.writeByte(0xBB);
+withBom.writeByte(0xBF);
+withBom.writeUTFBytes("<root>bom</root>");
+var bomXml:XML = new XML(withBom);
+trace("bomXml.localName(): " + bomXml.localName());
+trace("bomXml.text(): " + bomXml.text());
+
+var listBytes:ByteArray = new ByteArray();
+listBytes.writeUTFBytes("<a>1</a><b>2</b>");
+var list:XMLList = new XMLList(listBytes);
+trace("list.length(): " + list.length());
+trace("list.toXMLString(): " + list.toXMLString());
@@ -0,0 +1,22 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+var xml:XML = <root xmlns:a="http://a" xmlns:b="http://b"><child xmlns:a="http://a2" xmlns:c="http://c"><leaf/></child></root>;
+
+function dump(label:String, node:XML):void {
+  var namespaces:Array = node.inScopeNamespaces();
+  trace(label + ": " + namespaces.length);
+  for each (var ns in namespaces) {
+    trace("  " + ns.prefix + " = " + ns.uri);
+  }
+}
+
+dump("root", xml);
+dump("child", xml.children()[0]);
+dump("leaf", xml.children()[0].children()[0]);
+
+var shadowed:XML = <outer xmlns:p="http://p1" xmlns:q="http://q"><middle xmlns:p="http://p2"><inner xmlns:p="http://p3" xmlns:r="http://r"/></middle></outer>;
+dump("shadowed middle", shadowed.children()[0]);
+dump("shadowed inner", shadowed.children()[0].children()[0]);
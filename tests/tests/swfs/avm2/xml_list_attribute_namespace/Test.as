@@ -0,0 +1,28 @@
+package {
+	import flash.display.Sprite;
+	public class Test extends Sprite { }
+}
+
+var xml:XML = <root xmlns:a="http://a.example" xmlns:b="http://b.example">
+	<item a:id="a1" b:id="b1" id="plain1" />
+	<item a:id="a2" id="plain2" />
+	<item b:id="b3" />
+</root>;
+
+var list:XMLList = xml.item;
+var nsA = new Namespace("http://a.example");
+var nsB = new Namespace("http://b.example");
+
+var fromA:XMLList = list.attribute(new QName(nsA, "id"));
+trace("a::id length: " + fromA.length());
+trace("a::id: " + fromA.toXMLString());
+
+var fromB:XMLList = list.attribute(new QName(nsB, "id"));
+trace("b::id length: " + fromB.length());
+trace("b::id: " + fromB.toXMLString());
+
+var anyNs:XMLList = list.attribute(new QName(null, "id"));
+trace("*::id length: " + anyNs.length());
+
+var missing:XMLList = list.attribute(new QName(new Namespace("http://c.example"), "id"));
+trace("c::id length: " + missing.length());
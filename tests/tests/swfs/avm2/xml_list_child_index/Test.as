@@ -0,0 +1,30 @@
+package {
+	import flash.display.Sprite;
+	public class Test extends Sprite { }
+}
+
+var xml:XML = <root>
+	<item><a>1</a><b>2</b></item>
+	<item><c>3</c></item>
+	<item>text</item>
+</root>;
+
+var list:XMLList = xml.item;
+
+var first:XMLList = list.child(0);
+trace("child(0) length: " + first.length());
+trace("child(0): " + first.toXMLString());
+
+var second:XMLList = list.child(1);
+trace("child(1) length: " + second.length());
+trace("child(1): " + second.toXMLString());
+
+var fromString:XMLList = list.child("0");
+trace("child('0') length: " + fromString.length());
+
+var outOfRange:XMLList = list.child(5);
+trace("child(5) length: " + outOfRange.length());
+
+var byName:XMLList = list.child("c");
+trace("child('c') length: " + byName.length());
+trace("child('c'): " + byName.toXMLString());
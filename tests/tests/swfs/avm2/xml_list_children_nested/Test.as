@@ -0,0 +1,30 @@
+package {
+	import flash.display.Sprite;
+	public class Test extends Sprite { }
+}
+
+var xml:XML = <root>
+	<group name="g1">
+		<entry id="1"><leaf>a</leaf><leaf>b</leaf></entry>
+		<entry id="2"><leaf>c</leaf></entry>
+	</group>
+	<group name="g2">
+		<entry id="3"><leaf>d</leaf></entry>
+	</group>
+</root>;
+
+var groups:XMLList = xml.group;
+trace("groups.length(): " + groups.length());
+
+var entries:XMLList = groups.children();
+trace("groups.children().length(): " + entries.length());
+for each (var entry:XML in entries) {
+	trace("entry " + entry.@id + " has " + entry.children().length() + " children");
+}
+
+var leaves:XMLList = entries.children();
+trace("entries.children().length(): " + leaves.length());
+trace("leaves: " + leaves.text().toXMLString());
+
+trace("xml.children().length(): " + xml.children().length());
+trace("xml.children().children().children().length(): " + xml.children().children().children().length());
@@ -0,0 +1,27 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = <root><a>1</a><b>2</b><z>3</z></root>;
+
+var list:XMLList = xml.a + xml.b;
+trace("length: " + list.length());
+trace(list.toXMLString());
+
+list[0].@id = "first";
+list[1].@id = "second";
+trace("xml.a.@id: " + xml.a.@id);
+trace("xml.b.@id: " + xml.b.@id);
+trace(xml.toXMLString());
+
+list[list.length()] = <c>4</c>;
+trace("length after append: " + list.length());
+trace(list.toXMLString());
+trace(xml.toXMLString());
+
+var empty:XMLList = new XMLList();
+trace("empty + list: " + (empty + list).length());
+trace("list + empty: " + (list + empty).length());
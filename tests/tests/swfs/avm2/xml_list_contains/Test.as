@@ -0,0 +1,20 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+var list:XMLList = <root><a>1</a><b>two</b><c><d/></c></root>.children();
+
+trace("contains(<a>1</a>): " + list.contains(<a>1</a>));
+trace("contains(<a>2</a>): " + list.contains(<a>2</a>));
+trace("contains(<c><d/></c>): " + list.contains(<c><d/></c>));
+trace("contains(<c><e/></c>): " + list.contains(<c><e/></c>));
+trace("contains(\"two\"): " + list.contains("two"));
+trace("contains(\"three\"): " + list.contains("three"));
+trace("contains(1): " + list.contains(1));
+trace("contains(null): " + list.contains(null));
+trace("contains(undefined): " + list.contains(undefined));
+trace("contains(new XMLList(\"<b>two</b>\")): " + list.contains(new XMLList("<b>two</b>")));
+trace("contains(list[1]): " + list.contains(list[1]));
+trace("contains({}): " + list.contains({}));
+trace("new XMLList().contains(undefined): " + new XMLList().contains(undefined));
@@ -0,0 +1,16 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var list:XMLList = new XMLList("<a>text<b>1</b><c>2</c><!-- comment --><b>3</b></a><d><b>4</b></d><e>only text</e>");
+
+trace("elements(): " + list.elements().toXMLString());
+trace("elements().length(): " + list.elements().length());
+trace("elements('*'): " + list.elements("*").length());
+trace("elements('b'): " + list.elements("b").toXMLString());
+trace("elements('c'): " + list.elements("c").toXMLString());
+trace("elements('missing'): " + list.elements("missing").length());
+trace("empty list: " + new XMLList().elements().length());
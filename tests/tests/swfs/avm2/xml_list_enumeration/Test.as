@@ -0,0 +1,22 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+var xml:XML = <root><one>1</one><many>a</many><many>b</many><many>c</many></root>;
+
+function dump(label:String, list:XMLList):void {
+    var count:int = 0;
+    for each (var item in list) {
+        trace(label + " value: " + item);
+        count++;
+    }
+    for (var name in list) {
+        trace(label + " name: " + name);
+    }
+    trace(label + " visited: " + count);
+}
+
+dump("empty", xml.missing);
+dump("single", xml.one);
+dump("three", xml.many);
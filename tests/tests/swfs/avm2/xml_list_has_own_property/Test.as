@@ -0,0 +1,25 @@
+package {
+	import flash.display.Sprite;
+	public class Test extends Sprite { }
+}
+
+var xml:XML = <root>
+	<item id="a"><name>First</name></item>
+	<item id="b"><name>Second</name></item>
+	<other />
+</root>;
+
+var list:XMLList = xml.item;
+
+trace("list.hasOwnProperty('0'): " + list.hasOwnProperty("0"));
+trace("list.hasOwnProperty('1'): " + list.hasOwnProperty("1"));
+trace("list.hasOwnProperty('2'): " + list.hasOwnProperty("2"));
+trace("list.hasOwnProperty('5'): " + list.hasOwnProperty("5"));
+trace("list.hasOwnProperty('name'): " + list.hasOwnProperty("name"));
+trace("list.hasOwnProperty('missing'): " + list.hasOwnProperty("missing"));
+trace("list.hasOwnProperty('@id'): " + list.hasOwnProperty("@id"));
+trace("list.hasOwnProperty('@missing'): " + list.hasOwnProperty("@missing"));
+
+var empty:XMLList = xml.missing;
+trace("empty.hasOwnProperty('0'): " + empty.hasOwnProperty("0"));
+trace("empty.hasOwnProperty('name'): " + empty.hasOwnProperty("name"));
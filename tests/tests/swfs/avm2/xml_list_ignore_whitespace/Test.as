@@ -0,0 +1,24 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+var source:String = "<a>  spaced  </a>\n  <b>\n\t<c/>\n</b>";
+
+function dump(list:XMLList):void {
+  trace("length: " + list.length());
+  trace("a text: [" + list[0].text() + "]");
+  var b:XML = list[list.length() - 1];
+  trace("b children: " + b.children().length());
+}
+
+trace("// XML.ignoreWhitespace = true");
+dump(new XMLList(source));
+
+trace("// XML.ignoreWhitespace = false");
+XML.ignoreWhitespace = false;
+dump(new XMLList(source));
+
+trace("// XML.ignoreWhitespace = true again");
+XML.ignoreWhitespace = true;
+dump(new XMLList(source));
@@ -0,0 +1,29 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.ignoreComments = false;
+XML.ignoreProcessingInstructions = false;
+
+var xml:XML = <root attr="value"><child/>text<![CDATA[cdata]]><!-- comment --><?pi data?></root>;
+
+trace("element: " + xml.child("child").nodeKind());
+trace("attribute: " + xml.attributes().nodeKind());
+trace("text: " + xml.text().nodeKind());
+var children:XMLList = xml.children();
+for (var i = 0; i < children.length(); i++) {
+    trace("child " + i + ": " + children[i].nodeKind());
+}
+
+try {
+    children.nodeKind();
+} catch (e) {
+    trace(e);
+}
+
+try {
+    new XMLList().nodeKind();
+} catch (e) {
+    trace(e);
+}
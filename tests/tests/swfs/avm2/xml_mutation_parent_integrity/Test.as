@@ -0,0 +1,40 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = <a><b>1</b></a>;
+
+xml.appendChild(<c/>);
+trace("appendChild: " + xml.toXMLString());
+trace("appended parent: " + xml.c[0].parent().name());
+
+xml.prependChild(<d/>);
+trace("prependChild: " + xml.toXMLString());
+trace("prepended parent: " + xml.d[0].parent().name());
+
+xml.b = "abc";
+trace("set existing element: " + xml.toXMLString());
+trace("set text parent: " + xml.b.children()[0].parent().name());
+
+xml.e = "def";
+trace("set new element: " + xml.toXMLString());
+trace("new element parent: " + xml.e[0].parent().name());
+
+xml.@attr = "value";
+trace("set attribute: " + xml.toXMLString());
+trace("attribute parent: " + xml.@attr[0].parent().name());
+
+delete xml.@attr;
+trace("delete attribute: " + xml.toXMLString());
+
+var removed:XML = xml.c[0];
+delete xml.c;
+trace("delete element: " + xml.toXMLString());
+trace("deleted element parent: " + removed.parent());
+
+var copy:XML = xml.copy();
+trace("copy: " + copy.toXMLString());
+trace("copied child parent: " + copy.b[0].parent().name());
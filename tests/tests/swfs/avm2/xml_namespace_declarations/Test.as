@@ -0,0 +1,28 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = <root xmlns="http://example.com/default" xmlns:a="http://example.com/a" xmlns:b="http://example.com/b" attr="1"><child xmlns:c="http://example.com/c"/></root>;
+
+var decls:Array = xml.namespaceDeclarations();
+trace("root declarations: " + decls.length);
+for each (var ns in decls) {
+    trace("  prefix: '" + ns.prefix + "' uri: " + ns.uri);
+}
+
+trace("root attributes: " + xml.attributes().length());
+
+var child:XML = xml.children()[0];
+var childDecls:Array = child.namespaceDeclarations();
+trace("child declarations: " + childDecls.length);
+for each (var childNs in childDecls) {
+    trace("  prefix: '" + childNs.prefix + "' uri: " + childNs.uri);
+}
+
+var text:XML = new XML("just text");
+trace("text declarations: " + text.namespaceDeclarations().length);
+
+trace(xml.toXMLString());
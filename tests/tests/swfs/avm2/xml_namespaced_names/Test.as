@@ -0,0 +1,32 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml = <root xmlns:a="http://a.example/" xmlns:b="http://b.example/">
+  <a:item>first</a:item>
+  <b:item>second</b:item>
+  <group><a:item>nested a</a:item><b:item>nested b</b:item></group>
+</root>;
+
+var a = new Namespace("http://a.example/");
+var b = new Namespace("http://b.example/");
+
+trace("xml.a::item:", xml.a::item);
+trace("xml.b::item:", xml.b::item);
+trace("xml.*::item.length():", xml.*::item.length());
+
+trace("xml.child(a::item):", xml.child(new QName(a, "item")));
+trace("xml.child(b::item):", xml.child(new QName(b, "item")));
+
+var aItems = xml.descendants(new QName(a, "item"));
+trace("xml.descendants(a::item):", aItems.length(), aItems[0], aItems[1]);
+var bItems = xml.descendants(new QName(b, "item"));
+trace("xml.descendants(b::item):", bItems.length(), bItems[0], bItems[1]);
+trace("xml..a::item.length():", xml..a::item.length());
+trace("xml..*::item.length():", xml..*::item.length());
+
+trace("xml.group.a::item:", xml.group.a::item);
+trace("xml.group.b::item:", xml.group.b::item);
@@ -0,0 +1,104 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+// Flash Player has no such limit; this checks Ruffle's own E4X node limit,
+// which test.toml lowers to 50 nodes.
+
+function repeat(str:String, doublings:int):String {
+  for (var i:int = 0; i < doublings; i++) {
+    str += str;
+  }
+  return str;
+}
+
+// 64 children plus the root is past the limit.
+try {
+  new XML("<root>" + repeat("<a/>", 6) + "</root>");
+  trace("parse: no error");
+} catch (e:Error) {
+  trace("parse: " + e.errorID);
+}
+
+// Each of these is 33 nodes, so they're fine on their own...
+var first:XML = new XML("<root>" + repeat("<a/>", 5) + "</root>");
+var second:XML = new XML("<root>" + repeat("<b/>", 5) + "</root>");
+trace("first children: " + first.children().length());
+trace("second children: " + second.children().length());
+
+// ...but joining them goes past it.
+try {
+  first.appendChild(second);
+  trace("appendChild: no error");
+} catch (e:Error) {
+  trace("appendChild: " + e.errorID);
+}
+trace("first children after appendChild: " + first.children().length());
+
+try {
+  second.prependChild(first);
+  trace("prependChild: no error");
+} catch (e:Error) {
+  trace("prependChild: " + e.errorID);
+}
+trace("second children after prependChild: " + second.children().length());
+
+// Replaced children no longer count towards the limit.
+first.setChildren(<c/>);
+try {
+  first.appendChild(second);
+  trace("appendChild after setChildren: no error");
+} catch (e:Error) {
+  trace("appendChild after setChildren: " + e.errorID);
+}
+trace("first children after setChildren and appendChild: " + first.children().length());
+
+// Growing a tree one node at a time stops at the limit.
+var grown:XML = <root/>;
+for (var i:int = 0; i < 100; i++) {
+  try {
+    grown.appendChild(<d/>);
+  } catch (e:Error) {
+    trace("appendChild in a loop: " + e.errorID + " after " + i + " children");
+    break;
+  }
+}
+
+// Deleted children no longer count towards the limit.
+var cycled:XML = <root/>;
+var cycleResult:String = "no error";
+for (var j:int = 0; j < 100; j++) {
+  try {
+    cycled.appendChild(<e/>);
+    delete cycled.e;
+  } catch (e:Error) {
+    cycleResult = String(e.errorID);
+    break;
+  }
+}
+trace("appendChild and delete in a loop: " + cycleResult + ", " + cycled.children().length() + " children");
+
+// Setting a new element adds both the element and its text.
+var assigned:XML = <root/>;
+for (var k:int = 0; k < 100; k++) {
+  try {
+    assigned["n" + k] = "text";
+  } catch (e:Error) {
+    trace("setting elements in a loop: " + e.errorID + " after " + k + " children");
+    break;
+  }
+}
+
+// Replacing the text of an element doesn't grow the tree.
+var replaced:XML = <root><n>text</n></root>;
+var replaceResult:String = "no error";
+for (var m:int = 0; m < 100; m++) {
+  try {
+    replaced.n = "text " + m;
+  } catch (e:Error) {
+    replaceResult = String(e.errorID);
+    break;
+  }
+}
+trace("replacing text in a loop: " + replaceResult + ", " + replaced.n);
@@ -0,0 +1,33 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = <root><a>world</a></root>;
+var a:XML = xml.a[0];
+a.prependChild(" ");
+a.prependChild("hello");
+a.prependChild("");
+xml.prependChild("x");
+xml.prependChild("");
+
+trace("root children before: " + xml.children().length());
+trace("a children before: " + a.children().length());
+
+trace("returns receiver: " + (xml.normalize() === xml));
+trace("root children after: " + xml.children().length());
+trace("a children after: " + a.children().length());
+trace("a text: " + a.text());
+trace("toXMLString: " + xml.toXMLString());
+
+var b:XML = <b>foo</b>;
+b.prependChild("bar");
+var list:XMLList = b.children();
+trace("list length before: " + list.length());
+trace("list returns receiver: " + (list.normalize() === list));
+trace("list length after: " + list.length());
+trace("list: " + list.toString());
+trace("b children after: " + b.children().length());
+trace("b: " + b.toXMLString());
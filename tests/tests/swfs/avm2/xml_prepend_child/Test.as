@@ -0,0 +1,29 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = <a><c/></a>;
+trace("before: " + xml.toXMLString());
+
+xml.prependChild(<b/>);
+trace("prepend XML: " + xml.toXMLString());
+
+xml.prependChild("text");
+trace("prepend String: " + xml.toXMLString());
+
+var list:XMLList = new XMLList("<x/><y/>");
+xml.prependChild(list);
+trace("prepend XMLList: " + xml.toXMLString());
+
+trace("returns receiver: " + (xml.prependChild(<z/>) === xml));
+trace("after: " + xml.toXMLString());
+trace("children: " + xml.children().length());
+trace("parent of first child: " + xml.children()[0].parent().name());
+
+var text:XML = xml.children()[3];
+trace("text nodeKind: " + text.nodeKind());
+trace("text returns receiver: " + (text.prependChild(<q/>) === text));
+trace("text after: " + text.toXMLString());
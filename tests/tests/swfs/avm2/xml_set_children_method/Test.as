@@ -0,0 +1,21 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+
+var xml:XML = <root><a/><b>text</b></root>;
+
+var result:XML = xml.setChildren(<c>new</c>);
+trace("returns receiver: " + (result === xml));
+trace(xml.toXMLString());
+trace("parent is root: " + (xml.c.parent() === xml));
+
+xml.setChildren(new XMLList("<d/><e/>"));
+trace(xml.toXMLString());
+trace("children: " + xml.children().length());
+
+xml.setChildren("plain text");
+trace(xml.toXMLString());
+trace("child kind: " + xml.children()[0].nodeKind());
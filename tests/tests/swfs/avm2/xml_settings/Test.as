@@ -3,14 +3,46 @@ package {
   public class Test extends Sprite { }
 }
 
-var settings = XML.settings();
-trace("XML.settings(): " + settings);
+function dump(settings:Object):void {
+  trace("ignoreComments: " + settings.ignoreComments);
+  trace("ignoreProcessingInstructions: " + settings.ignoreProcessingInstructions);
+  trace("ignoreWhitespace: " + settings.ignoreWhitespace);
+  trace("prettyPrinting: " + settings.prettyPrinting);
+  trace("prettyIndent: " + settings.prettyIndent);
+}
+
+trace("// XML.defaultSettings()");
+dump(XML.defaultSettings());
+
+trace("// XML.settings()");
+dump(XML.settings());
+
+var source:String = "<root><!-- comment --><?pi data?><a/></root>";
+trace("// default parse: children");
+trace(new XML(source).children().length());
+
+trace("// XML.setSettings({ignoreComments: false, prettyIndent: 4})");
+XML.setSettings({ignoreComments: false, prettyIndent: 4});
+dump(XML.settings());
+trace("// parse with comments kept: children");
+trace(new XML(source).children().length());
+
+trace("// XML.setSettings with wrong types");
+XML.setSettings({ignoreProcessingInstructions: "no", ignoreWhitespace: 0});
+dump(XML.settings());
+
+trace("// XML.ignoreProcessingInstructions = false");
+XML.ignoreProcessingInstructions = false;
+trace(XML.settings().ignoreProcessingInstructions);
+trace("// parse with comments and processing instructions kept: children");
+trace(new XML(source).children().length());
 
-trace("settings.ignoreComments = " + settings.ignoreComments);
-trace("settings.ignoreWhitespace = " + settings.ignoreWhitespace);
-trace("settings.ignoreProcessingInstructions = " + settings.ignoreProcessingInstructions);
-trace("settings.prettyIndent = " + settings.prettyIndent);
-trace("settings.prettyPrinting = " + settings.prettyPrinting);
+trace("// XML.setSettings()");
+XML.setSettings();
+dump(XML.settings());
+trace(new XML(source).children().length());
 
-// Stub
-XML.setSettings(settings);
+trace("// XML.setSettings(XML.defaultSettings()) after changes");
+XML.prettyPrinting = false;
+XML.setSettings(XML.defaultSettings());
+trace(XML.prettyPrinting);
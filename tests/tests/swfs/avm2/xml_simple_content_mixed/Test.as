@@ -0,0 +1,27 @@
+package {
+  import flash.display.Sprite;
+  public class Test extends Sprite { }
+}
+
+XML.prettyPrinting = false;
+XML.ignoreComments = false;
+XML.ignoreProcessingInstructions = false;
+
+var root:XML = new XML("<root>text<![CDATA[cdata]]><!--comment--><?pi data?></root>");
+var list:XMLList = root.children();
+
+trace("list.length(): " + list.length());
+trace("list.hasSimpleContent(): " + list.hasSimpleContent());
+trace("list.toString(): " + list.toString());
+trace("list.toXMLString():");
+trace(list.toXMLString());
+trace("root.toString(): " + root.toString());
+trace("root.toXMLString(): " + root.toXMLString());
+
+trace("list[0].toString(): " + list[0].toString());
+trace("list[1].toString(): " + list[1].toString());
+trace("list[2].toString(): " + list[2].toString());
+trace("list[3].toString(): " + list[3].toString());
+
+var reversed:XML = new XML("<root><!--comment--><![CDATA[cdata]]>text</root>");
+trace("reversed.children().toString(): " + reversed.children().toString());
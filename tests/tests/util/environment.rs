@@ -23,6 +23,7 @@ fn create_wgpu_device() -> Option<(wgpu::Instance, wgpu::Adapter, wgpu::Device,
         None,
         Default::default(),
         None,
+        None,
     ))
     .ok()
     .map(|(adapter, device, queue)| (instance, adapter, device, queue))
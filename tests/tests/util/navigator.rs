@@ -15,10 +15,13 @@ use url::{ParseError, Url};
 /// A `NavigatorBackend` used by tests that supports logging fetch requests.
 ///
 /// This can be used by tests that fetch data to verify that the request is correct.
+///
+/// Socket connections never succeed; they either fail or, with `socket_timeout`, time out.
 pub struct TestNavigatorBackend {
     spawner: NullSpawner,
     relative_base_path: PathBuf,
     log: Option<TestLogBackend>,
+    socket_timeout: bool,
 }
 
 impl TestNavigatorBackend {
@@ -26,11 +29,13 @@ impl TestNavigatorBackend {
         path: &Path,
         executor: &NullExecutor,
         log: Option<TestLogBackend>,
+        socket_timeout: bool,
     ) -> Result<Self, std::io::Error> {
         Ok(Self {
             spawner: executor.spawner(),
             relative_base_path: path.canonicalize()?,
             log,
+            socket_timeout,
         })
     }
 }
@@ -111,8 +116,13 @@ impl NavigatorBackend for TestNavigatorBackend {
             log.avm_trace("Navigator::connect_socket");
             log.avm_trace(&format!("    Host: {}; Port: {}", host, port));
         }
+        let state = if self.socket_timeout {
+            ConnectionState::TimedOut
+        } else {
+            ConnectionState::Failed
+        };
         sender
-            .send(SocketAction::Connect(handle, ConnectionState::Failed))
+            .send(SocketAction::Connect(handle, state))
             .expect("working channel send");
     }
 }
@@ -25,6 +25,7 @@ pub struct TestOptions {
     pub approximations: Option<Approximations>,
     pub player_options: PlayerOptions,
     pub log_fetch: bool,
+    pub socket_timeout: bool,
 }
 
 impl Default for TestOptions {
@@ -40,6 +41,7 @@ impl Default for TestOptions {
             approximations: None,
             player_options: PlayerOptions::default(),
             log_fetch: false,
+            socket_timeout: false,
         }
     }
 }
@@ -91,6 +93,7 @@ impl Approximations {
 #[serde(default, deny_unknown_fields)]
 pub struct PlayerOptions {
     max_execution_duration: Option<Duration>,
+    max_e4x_nodes: Option<usize>,
     viewport_dimensions: Option<ViewportDimensions>,
     with_renderer: Option<RenderOptions>,
     with_audio: bool,
@@ -107,6 +110,10 @@ impl PlayerOptions {
             player_builder = player_builder.with_max_execution_duration(max_execution_duration);
         }
 
+        if let Some(max_e4x_nodes) = self.max_e4x_nodes {
+            player_builder = player_builder.with_max_e4x_nodes(max_e4x_nodes);
+        }
+
         let (width, height) = if let Some(viewport_dimensions) = self.viewport_dimensions {
             player_builder = player_builder.with_viewport_dimensions(
                 viewport_dimensions.width,
@@ -103,6 +103,7 @@ pub fn run_swf(
         base_path,
         &executor,
         test.options.log_fetch.then(|| log.clone()),
+        test.options.socket_timeout,
     )?;
 
     let builder = PlayerBuilder::new()
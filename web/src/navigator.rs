@@ -290,14 +290,6 @@ impl NavigatorBackend for WebNavigatorBackend {
             let url = response.url();
             let status = response.status();
             let redirected = response.redirected();
-            if !response.ok() {
-                let error = Error::HttpNotOk(
-                    format!("HTTP status is not ok, got {}", response.status_text()),
-                    status,
-                    redirected,
-                );
-                return Err(ErrorResponse { url, error });
-            }
 
             let body: ArrayBuffer = JsFuture::from(response.array_buffer().map_err(|_| {
                 ErrorResponse {
@@ -319,6 +311,18 @@ impl NavigatorBackend for WebNavigatorBackend {
             })?;
             let body = Uint8Array::new(&body).to_vec();
 
+            // The body is still passed along for error statuses, as it may contain
+            // an error payload the movie wants to look at.
+            if !response.ok() {
+                let error = Error::HttpNotOk(
+                    format!("HTTP status is not ok, got {}", response.status_text()),
+                    status,
+                    redirected,
+                    body,
+                );
+                return Err(ErrorResponse { url, error });
+            }
+
             Ok(SuccessResponse {
                 url,
                 body,
@@ -361,14 +365,29 @@ impl NavigatorBackend for WebNavigatorBackend {
 
     fn connect_socket(
         &mut self,
-        _host: String,
-        _port: u16,
+        host: String,
+        port: u16,
         _timeout: Duration,
         handle: SocketHandle,
         _receiver: Receiver<Vec<u8>>,
         sender: Sender<SocketAction>,
     ) {
+        // If `allowNetworking` is set to `none`, sockets are not allowed to connect.
+        if self.allow_networking == NetworkingAccessMode::None {
+            tracing::warn!("SWF tried to open a socket, but opening a socket is not allowed");
+            sender
+                .send(SocketAction::Connect(handle, ConnectionState::Failed))
+                .expect("working channel send");
+            return;
+        }
+
         // FIXME: Add way to call out to JS code.
+        tracing::debug!(
+            host = %host,
+            port,
+            ?handle,
+            "Socket connection failed, sockets aren't supported on web yet"
+        );
         sender
             .send(SocketAction::Connect(handle, ConnectionState::Failed))
             .expect("working channel send");